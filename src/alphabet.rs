@@ -0,0 +1,139 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::word::LetterMask;
+
+/// The set of letters a pangram has to cover.
+///
+/// Letters are stored in uppercase; words are uppercased before being matched against them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alphabet {
+    letters: Vec<char>
+}
+
+impl Alphabet {
+    /// The most letters an alphabet may contain, limited by the width of the letter bitmask
+    pub const MAX_LETTERS: usize = LetterMask::BITS as usize;
+
+    /// Creates an alphabet from the given letters. Letters are uppercased and repeats are ignored.
+    pub fn new<I: IntoIterator<Item = char>>(letters: I) -> Result<Alphabet, AlphabetError> {
+        let mut output: Vec<char> = vec![];
+        for letter in letters.into_iter().filter(|c| !c.is_whitespace()) {
+            let mut uppercase = letter.to_uppercase();
+            let letter = match (uppercase.next(), uppercase.next()) {
+                (Some(upper), None) => upper,
+                _ => letter
+            };
+            if !output.contains(&letter) {
+                output.push(letter)
+            }
+        }
+
+        if output.is_empty() {
+            Err(AlphabetError::Empty)
+        } else if output.len() > Self::MAX_LETTERS {
+            Err(AlphabetError::TooManyLetters(output.len()))
+        } else {
+            Ok(Alphabet { letters: output })
+        }
+    }
+
+    /// The 26 letters of the English alphabet
+    pub fn english() -> Alphabet {
+        Self::preset("ABCDEFGHIJKLMNOPQRSTUVWXYZ")
+    }
+
+    /// The English alphabet plus Ä, Ö and Ü
+    pub fn german() -> Alphabet {
+        Self::preset("ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÜ")
+    }
+
+    /// The English alphabet plus Ñ
+    pub fn spanish() -> Alphabet {
+        Self::preset("ABCDEFGHIJKLMNÑOPQRSTUVWXYZ")
+    }
+
+    /// The 24 letters of the Greek alphabet
+    pub fn greek() -> Alphabet {
+        Self::preset("ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡΣΤΥΦΧΨΩ")
+    }
+
+    /// The 33 letters of the Russian (Cyrillic) alphabet
+    pub fn russian() -> Alphabet {
+        Self::preset("АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ")
+    }
+
+    /// The 29 letters of the Turkish alphabet, with dotted İ and dotless I as separate letters
+    pub fn turkish() -> Alphabet {
+        Self::preset("ABCÇDEFGĞHIİJKLMNOÖPRSŞTUÜVYZ")
+    }
+
+    fn preset(letters: &str) -> Alphabet {
+        Alphabet { letters: letters.chars().collect() }
+    }
+
+    /// The letters of the alphabet, in the order they were given
+    pub fn letters(&self) -> &[char] {
+        &self.letters
+    }
+
+    /// The number of letters in the alphabet
+    pub fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Always false, as an alphabet can't be created without letters
+    pub fn is_empty(&self) -> bool {
+        self.letters.is_empty()
+    }
+
+    /// Whether the (uppercase) letter belongs to the alphabet
+    pub fn contains(&self, letter: char) -> bool {
+        self.letters.contains(&letter)
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Alphabet {
+        Alphabet::english()
+    }
+}
+
+impl FromStr for Alphabet {
+    type Err = AlphabetError;
+
+    /// Parses either the name of a preset alphabet (english, german, spanish, greek, russian,
+    /// turkish) or the letters of a custom alphabet
+    fn from_str(s: &str) -> Result<Alphabet, AlphabetError> {
+        match s.to_lowercase().as_str() {
+            "english" => Ok(Alphabet::english()),
+            "german" => Ok(Alphabet::german()),
+            "spanish" => Ok(Alphabet::spanish()),
+            "greek" => Ok(Alphabet::greek()),
+            "russian" | "cyrillic" => Ok(Alphabet::russian()),
+            "turkish" => Ok(Alphabet::turkish()),
+            _ => Alphabet::new(s.chars())
+        }
+    }
+}
+
+/// Reasons an [`Alphabet`] could not be created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlphabetError {
+    Empty,
+    TooManyLetters(usize)
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlphabetError::Empty => write!(f, "an alphabet needs at least one letter"),
+            AlphabetError::TooManyLetters(count) => write!(
+                f, "an alphabet can have at most {} letters, but {count} were given", Alphabet::MAX_LETTERS
+            )
+        }
+    }
+}
+
+impl Error for AlphabetError {}
@@ -0,0 +1,181 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use itertools::Itertools;
+
+// One bit per constraint, set when a word (or some word of a pangram) meets the constraint
+pub(crate) type ConstraintMask = u64;
+
+/// A test of a single word. Letters are compared case-insensitively, against the sanitized word.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Predicate {
+    /// The word starts with these letters
+    StartsWith(String),
+    /// The word ends with these letters
+    EndsWith(String),
+    /// The word contains every one of these letters
+    ContainsAll(String),
+    /// The word contains at least one of these letters
+    ContainsAny(String),
+    /// The word has this letter at this position (starting from 1)
+    LetterAt(usize, char),
+    /// The word is exactly this one
+    Word(String)
+}
+
+impl Predicate {
+    /// Whether a (sanitized, uppercase) word passes the test
+    pub fn matches(&self, word: &str) -> bool {
+        match self {
+            Predicate::StartsWith(letters) => word.starts_with(&letters.to_uppercase()),
+            Predicate::EndsWith(letters) => word.ends_with(&letters.to_uppercase()),
+            Predicate::ContainsAll(letters) => letters.to_uppercase().chars().all(|letter| word.contains(letter)),
+            Predicate::ContainsAny(letters) => letters.to_uppercase().chars().any(|letter| word.contains(letter)),
+            Predicate::LetterAt(position, letter) => {
+                let mut expected = letter.to_uppercase();
+                position.checked_sub(1)
+                    .and_then(|index| word.chars().nth(index))
+                    .is_some_and(|found| expected.next() == Some(found) && expected.next().is_none())
+            },
+            Predicate::Word(name) => word == name.to_uppercase()
+        }
+    }
+}
+
+/// Which of a pangram's words have to pass a [`Predicate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quantifier {
+    /// At least one word
+    SomeWord,
+    /// Every word
+    EveryWord,
+    /// No word
+    NoWord
+}
+
+/// A condition on the words of a pangram, such as "some word starts with Q" or "no word contains
+/// both X and Z". Conditions on every word (or no word) leave words out of the search; conditions
+/// on some word are tracked as the search goes, so branches that can't meet them are abandoned.
+///
+/// Constraints can also be parsed from `QUANTIFIER:PREDICATE=VALUE`, where the quantifier is
+/// `some`, `every` or `no`, and the predicate is `starts-with`, `ends-with`, `contains` (all of
+/// the letters), `contains-any`, `letter-at` (e.g. `letter-at=2,A`) or `word` (e.g. `word=CIGAR`):
+///
+/// ```
+/// use pangram_finder::{Constraint, Predicate};
+///
+/// let constraint: Constraint = "no:contains=XZ".parse().unwrap();
+/// assert_eq!(constraint, Constraint::no_word(Predicate::ContainsAll("XZ".to_owned())));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Constraint {
+    pub(crate) quantifier: Quantifier,
+    pub(crate) predicate: Predicate
+}
+
+impl Constraint {
+    /// At least one word of each pangram has to pass the test
+    pub fn some_word(predicate: Predicate) -> Constraint {
+        Constraint { quantifier: Quantifier::SomeWord, predicate }
+    }
+
+    /// Every word of each pangram has to pass the test
+    pub fn every_word(predicate: Predicate) -> Constraint {
+        Constraint { quantifier: Quantifier::EveryWord, predicate }
+    }
+
+    /// No word of any pangram may pass the test
+    pub fn no_word(predicate: Predicate) -> Constraint {
+        Constraint { quantifier: Quantifier::NoWord, predicate }
+    }
+
+    /// Which words have to pass the test
+    pub fn quantifier(&self) -> Quantifier {
+        self.quantifier
+    }
+
+    /// The test
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    // Whether a word can be part of any pangram, as far as this constraint is concerned
+    pub(crate) fn allows(&self, word: &str) -> bool {
+        match self.quantifier {
+            Quantifier::SomeWord => true,
+            Quantifier::EveryWord => self.predicate.matches(word),
+            Quantifier::NoWord => !self.predicate.matches(word)
+        }
+    }
+
+    // Whether a word meets the constraint for the whole pangram. Every word that is allowed at all
+    // meets constraints on every word (or no word); only some words meet constraints on some word.
+    fn is_met_by(&self, word: &str) -> bool {
+        match self.quantifier {
+            Quantifier::SomeWord => self.predicate.matches(word),
+            Quantifier::EveryWord | Quantifier::NoWord => self.allows(word)
+        }
+    }
+}
+
+// The constraints (as bits, in order) that a word meets
+pub(crate) fn constraints_met(constraints: &[Constraint], word: &str) -> ConstraintMask {
+    constraints
+        .iter()
+        .enumerate()
+        .filter(|(_, constraint)| constraint.is_met_by(word))
+        .fold(0, |acc, (constraint_index, _)| acc | (1 << constraint_index))
+}
+
+// The mask of a pangram that meets every constraint
+pub(crate) fn all_constraints(constraints: &[Constraint]) -> ConstraintMask {
+    ConstraintMask::MAX.checked_shr(ConstraintMask::BITS - constraints.len() as u32).unwrap_or(0)
+}
+
+impl FromStr for Constraint {
+    type Err = ParseConstraintError;
+
+    fn from_str(s: &str) -> Result<Constraint, ParseConstraintError> {
+        let error = || ParseConstraintError(s.to_owned());
+        let (quantifier, predicate) = s.split_once(':').ok_or_else(error)?;
+        let (name, value) = predicate.split_once('=').ok_or_else(error)?;
+        if value.is_empty() {
+            return Err(error())
+        }
+
+        let predicate = match name.trim().to_lowercase().as_str() {
+            "starts-with" => Predicate::StartsWith(value.to_owned()),
+            "ends-with" => Predicate::EndsWith(value.to_owned()),
+            "contains" => Predicate::ContainsAll(value.to_owned()),
+            "contains-any" => Predicate::ContainsAny(value.to_owned()),
+            "letter-at" => {
+                let (position, letter) = value.split_once(',').ok_or_else(error)?;
+                let position = position.trim().parse().map_err(|_| error())?;
+                let letter = letter.trim().chars().exactly_one().map_err(|_| error())?;
+                Predicate::LetterAt(position, letter)
+            },
+            "word" => Predicate::Word(value.to_owned()),
+            _ => return Err(error())
+        };
+
+        match quantifier.trim().to_lowercase().as_str() {
+            "some" => Ok(Constraint::some_word(predicate)),
+            "every" | "all" => Ok(Constraint::every_word(predicate)),
+            "no" | "none" => Ok(Constraint::no_word(predicate)),
+            _ => Err(error())
+        }
+    }
+}
+
+/// The error returned when parsing a malformed [`Constraint`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseConstraintError(String);
+
+impl fmt::Display for ParseConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid constraint \"{}\" (expected e.g. some:starts-with=Q, no:contains=XZ, every:letter-at=2,A or some:word=CIGAR)", self.0)
+    }
+}
+
+impl Error for ParseConstraintError {}
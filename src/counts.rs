@@ -0,0 +1,56 @@
+use std::ops::RangeInclusive;
+
+/// The number of pangrams found, broken down by how many words they use
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PangramCounts {
+    pub(crate) min_words: usize,
+    pub(crate) by_size: Vec<u64> // Indexed by number of words, up to the maximum solution size
+}
+
+impl PangramCounts {
+    /// No pangrams yet, for sizes in this range of words
+    pub fn new(words: RangeInclusive<usize>) -> PangramCounts {
+        PangramCounts { min_words: *words.start(), by_size: vec![0; *words.end() + 1] }
+    }
+
+    /// Counts one more pangram of this many words
+    pub fn add(&mut self, words: usize) {
+        if words >= self.by_size.len() {
+            self.by_size.resize(words + 1, 0);
+        }
+        self.by_size[words] += 1;
+    }
+
+    /// The fewest words a counted pangram may use
+    pub fn min_words(&self) -> usize {
+        self.min_words
+    }
+
+    /// The most words a counted pangram may use
+    pub fn max_words(&self) -> usize {
+        self.by_size.len().saturating_sub(1)
+    }
+
+    /// The total number of pangrams
+    pub fn total(&self) -> u64 {
+        self.by_size().map(|(_, count)| count).sum()
+    }
+
+    /// The number of pangrams that use exactly this many words
+    pub fn of_size(&self, words: usize) -> u64 {
+        if words < self.min_words {
+            return 0
+        }
+        self.by_size.get(words).copied().unwrap_or(0)
+    }
+
+    /// The number of pangrams of each size within the search limits, smallest first,
+    /// including sizes with no pangrams
+    pub fn by_size(&self) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.by_size
+            .iter()
+            .copied()
+            .enumerate()
+            .skip(self.min_words)
+    }
+}
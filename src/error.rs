@@ -0,0 +1,73 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use itertools::Itertools;
+
+use crate::word_list::WordListError;
+
+/// Reasons a word list could not be read or a pangram search could not be run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The word list could not be read from this file (or from stdin, without a path)
+    Io { path: Option<PathBuf>, kind: io::ErrorKind, message: String },
+    /// This line of the word list (numbered from 1) is not valid UTF-8
+    InvalidEncoding { line: usize },
+    /// The word list doesn't follow its format
+    WordList(WordListError),
+    /// No words are left in the word list after sanitizing
+    EmptyWordList,
+    /// This line of the word list (numbered from 1) has characters outside the alphabet, and
+    /// [`NonLetters::Fail`](crate::NonLetters::Fail) is set
+    InvalidWord { line: usize, word: String },
+    /// No word in the word list contains these letters of the alphabet
+    AlphabetNotCovered { missing: Vec<char> },
+    /// No pangram can have at least `min` and at most `max` words
+    SolutionSizeOutOfRange { min: usize, max: usize },
+    /// More constraints were given than the search can track
+    TooManyConstraints { max: usize }
+}
+
+impl Error {
+    /// An [`Error::Io`] for reading this file, or stdin without a path
+    pub fn io(path: Option<PathBuf>, error: io::Error) -> Error {
+        Error::Io { path, kind: error.kind(), message: error.to_string() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path: Some(path), message, .. } => write!(f, "could not read {}: {message}", path.display()),
+            Error::Io { path: None, message, .. } => write!(f, "could not read the word list: {message}"),
+            Error::InvalidEncoding { line } => write!(f, "line {line} of the word list is not valid UTF-8"),
+            Error::WordList(error) => write!(f, "could not read the word list: {error}"),
+            Error::EmptyWordList => write!(f, "the word list has no words"),
+            Error::InvalidWord { line, word } => write!(
+                f, "line {line}: \"{word}\" has characters outside the alphabet"
+            ),
+            Error::AlphabetNotCovered { missing } => write!(
+                f, "no word contains the letter(s) {}, so no pangram can exist", missing.iter().join(", ")
+            ),
+            Error::SolutionSizeOutOfRange { min, max } => write!(
+                f, "no pangram can have at least {min} and at most {max} words"
+            ),
+            Error::TooManyConstraints { max } => write!(f, "too many constraints (at most {max} are supported)")
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::WordList(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<WordListError> for Error {
+    fn from(error: WordListError) -> Error {
+        Error::WordList(error)
+    }
+}
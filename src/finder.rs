@@ -0,0 +1,561 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::sync::{Arc, OnceLock};
+
+use itertools::Itertools;
+
+use crate::alphabet::Alphabet;
+use crate::constraint::{Constraint, ConstraintMask, Predicate};
+use crate::counts::PangramCounts;
+use crate::error::Error;
+use crate::sanitize::{CaseMapping, NonLetters, Normalization, SanitizeReport, Sanitized, SanitizedString};
+use crate::score::SortBy;
+use crate::search::{Minimality, SearchOptions, SearchStructure};
+use crate::solution::Solution;
+use crate::word::Word;
+use crate::word_list::WordListEntry;
+
+/// Searches a word list for pangrams.
+///
+/// ```
+/// use pangram_finder::PangramFinder;
+///
+/// let words = ["ABCDE", "FGHIJ", "KLMNO", "PQRST", "UVWXYZ"];
+/// let solutions = PangramFinder::new(words).max_words(5).find()?;
+/// assert_eq!(solutions.len(), 1);
+/// # Ok::<(), pangram_finder::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct PangramFinder {
+    words: Vec<String>,
+    lines: Vec<usize>,
+    required_words: Vec<String>,
+    excluded_words: Vec<String>,
+    constraints: Vec<Constraint>,
+    alphabet: Alphabet,
+    non_letters: NonLetters,
+    normalization: Normalization,
+    min_word_length: usize,
+    max_word_length: usize,
+    most_frequent: Option<usize>,
+    target: Option<Alphabet>,
+    only_target_letters: bool,
+    allow_missing_letters: bool,
+    max_missing_letters: usize,
+    disjoint: bool,
+    distinct_letters: bool,
+    minimality: Minimality,
+    max_words: usize,
+    min_words: usize,
+    sort_by: SortBy,
+    top: Option<usize>,
+    threads: usize,
+    sanitized: OnceLock<SanitizedWordList> // Worked out the first time it's needed
+}
+
+// The word list after sanitizing and filtering, shared by everything that needs it so the words
+// are only sanitized once
+#[derive(Debug, Clone)]
+struct SanitizedWordList {
+    words: Vec<SanitizedString>, // The words to search, in rank order
+    required_words: Vec<String>, // Without duplicates
+    word_list_is_empty: bool, // Whether sanitizing left out every word of the word list
+    report: SanitizeReport,
+    first_rejected: Option<usize> // Index of the first word with characters outside the alphabet
+}
+
+impl PangramFinder {
+    /// Default maximum number of words to use for finding pangrams
+    pub const DEFAULT_MAX_WORDS: usize = 6;
+
+    /// Creates a finder over the given words. Words are uppercased and (by default) stripped of
+    /// anything that isn't in the alphabet; empty and duplicate words are ignored.
+    pub fn new<I, S>(words: I) -> PangramFinder
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>
+    {
+        let words: Vec<String> = words.into_iter().map(|word| word.as_ref().to_owned()).collect();
+        PangramFinder {
+            lines: (1..=words.len()).collect(),
+            words,
+            required_words: vec![],
+            excluded_words: vec![],
+            constraints: vec![],
+            alphabet: Alphabet::default(),
+            non_letters: NonLetters::Strip,
+            normalization: Normalization::default(),
+            min_word_length: 1,
+            max_word_length: usize::MAX,
+            most_frequent: None,
+            target: None,
+            only_target_letters: false,
+            allow_missing_letters: false,
+            max_missing_letters: 0,
+            disjoint: false,
+            distinct_letters: false,
+            minimality: Minimality::All,
+            max_words: Self::DEFAULT_MAX_WORDS,
+            min_words: 1,
+            sort_by: SortBy::Words,
+            top: None,
+            threads: 1,
+            sanitized: OnceLock::new()
+        }
+    }
+
+    /// Creates a finder over the words read from a word list, so that errors name the line each
+    /// word came from rather than its position in the list. See [`PangramFinder::new`].
+    pub fn from_word_list(entries: Vec<WordListEntry>) -> PangramFinder {
+        let (words, lines): (Vec<String>, Vec<usize>) = entries.into_iter().map(|entry| (entry.word, entry.line)).unzip();
+        PangramFinder { lines, ..PangramFinder::new(words) }
+    }
+
+    /// Only finds pangrams that contain all of these words. They don't have to be in the word
+    /// list, and they count towards [`PangramFinder::max_words`]. Unless only irreducible
+    /// pangrams are wanted, a required word can be one whose letters the other words already
+    /// cover. Each one works like a [`Predicate::Word`] constraint on some word, so it counts
+    /// towards the 64 constraints.
+    pub fn require_words<I, S>(mut self, words: I) -> PangramFinder
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>
+    {
+        self.required_words.extend(words.into_iter().map(|word| word.as_ref().to_owned()));
+        self.word_list_changed()
+    }
+
+    /// Leaves these words out of the search, as if they weren't in the word list
+    pub fn exclude_words<I, S>(mut self, words: I) -> PangramFinder
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>
+    {
+        self.excluded_words.extend(words.into_iter().map(|word| word.as_ref().to_owned()));
+        self.word_list_changed()
+    }
+
+    /// Only finds pangrams whose words meet all of these constraints (at most 64 in total)
+    pub fn constraints<I>(mut self, constraints: I) -> PangramFinder
+    where
+        I: IntoIterator<Item = Constraint>
+    {
+        self.constraints.extend(constraints);
+        self
+    }
+
+    /// Sets the alphabet pangrams have to cover (English by default)
+    pub fn alphabet(mut self, alphabet: Alphabet) -> PangramFinder {
+        self.alphabet = alphabet;
+        self.word_list_changed()
+    }
+
+    /// Sets what to do with words containing characters outside the alphabet (they are stripped
+    /// by default)
+    pub fn non_letters(mut self, non_letters: NonLetters) -> PangramFinder {
+        self.non_letters = non_letters;
+        self.word_list_changed()
+    }
+
+    /// When set, letters outside the alphabet are folded into letters of the alphabet before
+    /// anything is stripped: accents are removed (É becomes E) and ligatures are spelled out
+    /// (Æ becomes AE). Letters of the alphabet are kept as they are.
+    pub fn fold_diacritics(mut self, fold_diacritics: bool) -> PangramFinder {
+        self.normalization.fold_diacritics = fold_diacritics;
+        self.word_list_changed()
+    }
+
+    /// Sets how words are uppercased (language-neutral by default)
+    pub fn case_mapping(mut self, case_mapping: CaseMapping) -> PangramFinder {
+        self.normalization.case_mapping = case_mapping;
+        self.word_list_changed()
+    }
+
+    /// Leaves out words with fewer letters than this, after sanitizing
+    pub fn min_word_length(mut self, min_word_length: usize) -> PangramFinder {
+        self.min_word_length = min_word_length;
+        self.word_list_changed()
+    }
+
+    /// Leaves out words with more letters than this, after sanitizing
+    pub fn max_word_length(mut self, max_word_length: usize) -> PangramFinder {
+        self.max_word_length = max_word_length;
+        self.word_list_changed()
+    }
+
+    /// Sets both the minimum and maximum word length, e.g. `5..=5` for only five-letter words
+    pub fn word_lengths(self, word_lengths: RangeInclusive<usize>) -> PangramFinder {
+        self.min_word_length(*word_lengths.start()).max_word_length(*word_lengths.end())
+    }
+
+    /// Only searches the first `most_frequent` words of the word list that pass the other
+    /// filters. Words are assumed to be listed most frequent first, as in a frequency list.
+    pub fn most_frequent(mut self, most_frequent: usize) -> PangramFinder {
+        self.most_frequent = Some(most_frequent);
+        self.word_list_changed()
+    }
+
+    /// Sets the letters pangrams have to cover, such as A to M or the letters of "STRINGER",
+    /// instead of the whole alphabet. Words can still contain other letters of the alphabet.
+    pub fn target(mut self, target: Alphabet) -> PangramFinder {
+        self.target = Some(target);
+        self.word_list_changed()
+    }
+
+    /// When set, words containing letters outside the [`PangramFinder::target`] are left out
+    /// of the search, so pangrams cover exactly the target letters
+    pub fn only_target_letters(mut self, only_target_letters: bool) -> PangramFinder {
+        self.only_target_letters = only_target_letters;
+        self.word_list_changed()
+    }
+
+    /// When set, letters of the alphabet that no word contains are left out of the search
+    /// instead of causing [`Error::AlphabetNotCovered`], as long as some letter is left to cover
+    pub fn allow_missing_letters(mut self, allow_missing_letters: bool) -> PangramFinder {
+        self.allow_missing_letters = allow_missing_letters;
+        self
+    }
+
+    /// Also reports near-pangrams that leave up to this many letters of the alphabet uncovered
+    /// (none by default). Letters that no word contains count towards this limit.
+    pub fn max_missing_letters(mut self, max_missing_letters: usize) -> PangramFinder {
+        self.max_missing_letters = max_missing_letters;
+        self
+    }
+
+    /// When set, the words of a pangram may not share any letters, as in the
+    /// "five words with twenty-five unique letters" problem
+    pub fn disjoint(mut self, disjoint: bool) -> PangramFinder {
+        self.disjoint = disjoint;
+        self
+    }
+
+    /// When set, words that repeat a letter (like "SISSY") are left out of the search
+    pub fn distinct_letters(mut self, distinct_letters: bool) -> PangramFinder {
+        self.distinct_letters = distinct_letters;
+        self.word_list_changed()
+    }
+
+    /// Sets whether to report pangrams that contain a word the others already cover
+    /// (all pangrams are reported by default)
+    pub fn minimality(mut self, minimality: Minimality) -> PangramFinder {
+        self.minimality = minimality;
+        self
+    }
+
+    /// Sets the maximum number of words a pangram may use
+    pub fn max_words(mut self, max_words: usize) -> PangramFinder {
+        self.max_words = max_words;
+        self
+    }
+
+    /// Sets the minimum number of words a reported pangram must use
+    pub fn min_words(mut self, min_words: usize) -> PangramFinder {
+        self.min_words = min_words;
+        self
+    }
+
+    /// Sets both the minimum and maximum number of words, e.g. `5..=5` for pangrams of exactly
+    /// five words
+    pub fn words(self, words: RangeInclusive<usize>) -> PangramFinder {
+        self.min_words(*words.start()).max_words(*words.end())
+    }
+
+    /// Sets the order [`PangramFinder::find`] returns solutions in (fewest words first by default)
+    pub fn sort_by(mut self, sort_by: SortBy) -> PangramFinder {
+        self.sort_by = sort_by;
+        self
+    }
+
+    /// Makes [`PangramFinder::find`] return only the best `top` solutions, by the
+    /// [`PangramFinder::sort_by`] metric
+    pub fn top(mut self, top: usize) -> PangramFinder {
+        self.top = Some(top);
+        self
+    }
+
+    /// Sets the number of threads to search with (one by default). The results are the same
+    /// whatever the number of threads.
+    pub fn threads(mut self, threads: usize) -> PangramFinder {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// The number of distinct words that will be searched
+    pub fn number_of_words(&self) -> usize {
+        self.sanitized().words.len()
+    }
+
+    /// The most words a pangram can use: [`PangramFinder::max_words`], or fewer if no pangram
+    /// could use that many (one word per letter to cover, plus the required words)
+    pub fn max_solution_size(&self) -> usize {
+        self.max_words.min(self.target_letters().len().saturating_add(self.required_words.len()))
+    }
+
+    /// The letters to cover (the target, or else the whole alphabet) that no word in the word
+    /// list contains, in order
+    pub fn missing_letters(&self) -> Vec<char> {
+        let occurences_of_each_letter = Self::count_letters(&self.sanitized().words);
+        self.target_letters()
+            .iter()
+            .filter(|letter| !occurences_of_each_letter.contains_key(letter))
+            .copied()
+            .collect()
+    }
+
+    fn target_letters(&self) -> &[char] {
+        self.target.as_ref().unwrap_or(&self.alphabet).letters()
+    }
+
+    /// How sanitizing changed the word list: which lines were stripped, split or left out
+    pub fn sanitize_report(&self) -> SanitizeReport {
+        self.sanitized().report
+    }
+
+    // Drops the sanitized word list, for settings that change it
+    fn word_list_changed(mut self) -> PangramFinder {
+        self.sanitized = OnceLock::new();
+        self
+    }
+
+    fn sanitized(&self) -> &SanitizedWordList {
+        self.sanitized.get_or_init(|| self.sanitize_word_list())
+    }
+
+    fn sanitize_word_list(&self) -> SanitizedWordList {
+        let mut report = SanitizeReport { lines: self.words.len(), ..SanitizeReport::default() };
+        let mut first_rejected = None;
+        let mut seen = HashSet::new();
+        let mut output = vec![];
+        for (line_index, line) in self.words.iter().enumerate() {
+            let words = match SanitizedString::sanitize_with(line, &self.alphabet, self.normalization, self.non_letters) {
+                Sanitized::Unchanged(word) => {
+                    report.unchanged += 1;
+                    vec![word]
+                },
+                Sanitized::Folded(word) => {
+                    report.folded += 1;
+                    vec![word]
+                },
+                Sanitized::Stripped(word) => {
+                    report.stripped += 1;
+                    vec![word]
+                },
+                Sanitized::Split(words) => {
+                    report.split += 1;
+                    words
+                },
+                Sanitized::Rejected => {
+                    report.rejected += 1;
+                    first_rejected.get_or_insert(line_index);
+                    vec![]
+                },
+                Sanitized::Blank => {
+                    report.blank += 1;
+                    vec![]
+                }
+            };
+
+            for word in words {
+                if !(self.min_word_length..=self.max_word_length).contains(&word.0.chars().count()) {
+                    report.wrong_length += 1;
+                } else if !seen.insert(word.0.clone()) {
+                    report.duplicates += 1;
+                } else {
+                    output.push(word);
+                }
+            }
+        }
+
+        // Keeps the words in their original order, so a word's position is its rank. Required
+        // words that aren't in the word list come last, even when only the most frequent words
+        // are kept.
+        let excluded: HashSet<String> = self.sanitize_all(&self.excluded_words).map(|word| word.0).collect();
+        let required: Vec<SanitizedString> = self.sanitize_all(&self.required_words).collect();
+        let word_list_is_empty = output.is_empty();
+        let mut seen = HashSet::new();
+        let words = output
+            .into_iter()
+            .filter(|line| !excluded.contains(&line.0))
+            .filter(|line| !self.distinct_letters || !line.has_repeated_letters())
+            .filter(|line| !self.only_target_letters || line.0.chars().all(|letter| self.target_letters().contains(&letter)))
+            .take(self.most_frequent.unwrap_or(usize::MAX))
+            .chain(required.iter().cloned())
+            .filter(|line| seen.insert(line.0.clone()))
+            .collect();
+        let required_words = required.into_iter().map(|word| word.0).unique().collect();
+        SanitizedWordList { words, required_words, word_list_is_empty, report, first_rejected }
+    }
+
+    fn sanitize_all<'a>(&'a self, words: &'a [String]) -> impl Iterator<Item = SanitizedString> + 'a {
+        words
+            .iter()
+            .map(|word| SanitizedString::sanitize(word, &self.alphabet, self.normalization))
+            .filter(|line| !line.0.is_empty())
+    }
+
+    fn count_letters(sanitized_strings: &[SanitizedString]) -> HashMap<char, u32> {
+        sanitized_strings
+            .iter()
+            .map(|s| s.get_unique_letters())
+            .collect::<String>()
+            .chars()
+            .fold(HashMap::new(), |mut map, letter| {
+                *map.entry(letter).or_insert(0) += 1;
+                map
+            })
+    }
+
+    /// Finds every pangram within the configured size limits, sorted by the
+    /// [`PangramFinder::sort_by`] metric, then by number of words, then alphabetically.
+    ///
+    /// Fails with [`Error::AlphabetNotCovered`] if more letters of the alphabet appear in no word
+    /// than [`PangramFinder::max_missing_letters`] allows, unless
+    /// [`PangramFinder::allow_missing_letters`] is set, or if no word contains any of the letters
+    /// to cover; with [`Error::EmptyWordList`] if no words are left after sanitizing; with
+    /// [`Error::SolutionSizeOutOfRange`] if the minimum number of words is above the maximum or
+    /// above [`PangramFinder::max_solution_size`]; and with [`Error::InvalidWord`] for the first
+    /// word with characters outside the alphabet when [`NonLetters::Fail`] is set.
+    pub fn find(&self) -> Result<Vec<Solution>, Error> {
+        let (search_structure, missing) = self.build_search()?;
+        let all_pangrams = if self.threads > 1 {
+            search_structure.find_pangrams_parallel(self.threads)
+        } else {
+            search_structure.solutions().collect()
+        };
+        Ok(self.sort(all_pangrams, &missing))
+    }
+
+    fn sort(&self, solutions: Vec<Solution>, missing: &[char]) -> Vec<Solution> {
+        solutions
+            .into_iter()
+            .filter_map(|solution| self.finish(solution, missing))
+            .map(|solution| (self.sort_by.key(&solution.score()), solution))
+            .sorted_by(|(a_key, a), (b_key, b)| {
+                a_key.cmp(b_key).then_with(|| a.len().cmp(&b.len())).then_with(|| a.cmp(b))
+            })
+            .map(|(_, solution)| solution)
+            .take(self.top.unwrap_or(usize::MAX))
+            .collect()
+    }
+
+    /// Finds pangrams lazily, yielding each one as soon as the search reaches it. Unlike
+    /// [`PangramFinder::find`], pangrams come in search order and the search always runs on the
+    /// calling thread, so stopping early (e.g. with [`Iterator::take`]) skips the rest of the work.
+    ///
+    /// Fails in the same cases as [`PangramFinder::find`].
+    pub fn solutions(&self) -> Result<impl Iterator<Item = Solution> + '_, Error> {
+        let (search_structure, missing) = self.build_search()?;
+        Ok(search_structure
+            .solutions()
+            .filter_map(move |solution| self.finish(solution, &missing)))
+    }
+
+    /// Finds the pangrams with the fewest total letters (all of them, if several tie), pruning
+    /// any branch of the search that is already longer than the shortest pangram found so far.
+    /// The results are sorted like [`PangramFinder::find`]. This search always runs on the
+    /// calling thread.
+    ///
+    /// Fails in the same cases as [`PangramFinder::find`].
+    pub fn find_shortest(&self) -> Result<Vec<Solution>, Error> {
+        let (search_structure, missing) = self.build_search()?;
+        Ok(self.sort(search_structure.shortest_pangrams(self.min_words), &missing))
+    }
+
+    /// Counts the pangrams [`PangramFinder::find`] would return, by number of words, without
+    /// building them. Words with the same letters are counted together, so this is much faster.
+    ///
+    /// Fails in the same cases as [`PangramFinder::find`].
+    pub fn count(&self) -> Result<PangramCounts, Error> {
+        let (search_structure, _) = self.build_search()?;
+        Ok(PangramCounts { min_words: self.min_words, by_size: search_structure.count_pangrams(self.threads) })
+    }
+
+    // Checks the options and the word list before building anything
+    fn validate(&self) -> Result<(), Error> {
+        if self.min_words > self.max_solution_size() || self.max_words == 0 {
+            return Err(Error::SolutionSizeOutOfRange { min: self.min_words, max: self.max_solution_size() })
+        }
+        if self.search_constraints().len() > ConstraintMask::BITS as usize {
+            return Err(Error::TooManyConstraints { max: ConstraintMask::BITS as usize })
+        }
+
+        let sanitized = self.sanitized();
+        if let (NonLetters::Fail, Some(index)) = (self.non_letters, sanitized.first_rejected) {
+            return Err(Error::InvalidWord { line: self.lines[index], word: self.words[index].trim().to_owned() })
+        }
+        if sanitized.word_list_is_empty && self.required_words.is_empty() {
+            return Err(Error::EmptyWordList)
+        }
+        Ok(())
+    }
+
+    // The constraints plus one for each required word, which only that word meets. The search
+    // reaches pangrams with required words like any other pangram, and can also add a required
+    // word once the other words cover every letter.
+    fn search_constraints(&self) -> Vec<Constraint> {
+        let required_words: Vec<Constraint> = self
+            .sanitized()
+            .required_words
+            .iter()
+            .map(|word| Constraint::some_word(Predicate::Word(word.clone())))
+            .collect();
+        self.constraints.iter().cloned().chain(required_words).collect()
+    }
+
+    fn build_search(&self) -> Result<(Arc<SearchStructure>, Vec<char>), Error> {
+        self.validate()?;
+
+        let missing = self.missing_letters();
+        let max_missing_letters = if missing.len() == self.target_letters().len() {
+            // Leaving every letter out would leave nothing to cover
+            return Err(Error::AlphabetNotCovered { missing })
+        } else if self.allow_missing_letters {
+            self.max_missing_letters
+        } else if missing.len() <= self.max_missing_letters {
+            self.max_missing_letters - missing.len()
+        } else {
+            return Err(Error::AlphabetNotCovered { missing })
+        };
+
+        let sanitized_strings = &self.sanitized().words;
+        let occurences_of_each_letter = Self::count_letters(sanitized_strings);
+
+        let mut letters_sorted_by_rarity: Vec<char> =
+            occurences_of_each_letter.keys().copied().collect();
+        // The letters to cover come first, so pangrams are the word sets covering a prefix of the
+        // search order. Ties are broken alphabetically so the order doesn't depend on the HashMap.
+        let is_target = |letter: &char| self.target_letters().contains(letter);
+        letters_sorted_by_rarity
+            .sort_by_key(|letter| (!is_target(letter), occurences_of_each_letter[letter], *letter));
+
+        let word_list: Vec<Word> = sanitized_strings
+            .iter()
+            .enumerate()
+            .map(|(rank, s)| Word::parse_string(s, &letters_sorted_by_rarity, rank))
+            .collect();
+
+        let options = SearchOptions {
+            letters_required: letters_sorted_by_rarity.iter().filter(|letter| is_target(letter)).count(),
+            max_solution_size: self.max_solution_size(),
+            max_missing_letters,
+            disjoint: self.disjoint,
+            minimality: self.minimality,
+            constraints: self.search_constraints()
+        };
+        let search_structure = SearchStructure::build(&letters_sorted_by_rarity, word_list, options);
+        Ok((Arc::new(search_structure), missing))
+    }
+
+    // Applies the options the search itself doesn't handle
+    fn finish(&self, mut solution: Solution, missing: &[char]) -> Option<Solution> {
+        if solution.words.len() < self.min_words {
+            return None
+        }
+
+        // Letters left out of the search entirely are missing from every solution
+        solution.missing_letters.extend_from_slice(missing);
+        solution.missing_letters.sort_by_key(|letter| self.alphabet.letters().iter().position(|l| l == letter));
+        Some(solution)
+    }
+}
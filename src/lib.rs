@@ -0,0 +1,30 @@
+//! Finds groups of words that together contain every letter of the alphabet.
+//!
+//! A pangram, in this context, is a list of words that contain at least one of each letter.
+//! [`PangramFinder`] takes a word list and search options and returns every [`Solution`].
+
+mod alphabet;
+mod constraint;
+mod counts;
+mod error;
+mod finder;
+mod output;
+mod sanitize;
+mod score;
+mod search;
+mod solution;
+mod word;
+mod word_list;
+
+pub use alphabet::{Alphabet, AlphabetError};
+pub use constraint::{Constraint, ParseConstraintError, Predicate, Quantifier};
+pub use counts::PangramCounts;
+pub use error::Error;
+pub use finder::PangramFinder;
+pub use output::{OutputFormat, ParseOutputFormatError, SolutionWriter, Summary};
+pub use sanitize::{CaseMapping, NonLetters, ParseCaseMappingError, ParseNonLettersError, SanitizeReport};
+pub use score::{ParseSortByError, Score, SortBy};
+pub use search::Minimality;
+pub use solution::Solution;
+pub use word::Word;
+pub use word_list::{Column, ParseWordListFormatError, WordListEntry, WordListError, WordListFormat};
@@ -1,181 +1,366 @@
-use std::collections::HashMap;
+use std::io::{self, Read};
+use std::num::ParseIntError;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::process;
+use std::str::FromStr;
+use std::thread;
+
+use clap::Parser;
 use itertools::Itertools;
+use pangram_finder::{
+    Alphabet, AlphabetError, CaseMapping, Column, Constraint, Error, Minimality, NonLetters, OutputFormat, PangramCounts, PangramFinder, Solution,
+    SolutionWriter, SortBy, Summary, WordListEntry, WordListFormat
+};
 
-const ALL_WORDS: &str = include_str!("wordle_answers.txt");
-const MAX_SOLUTION_SIZE: usize = 6; // Maximum number of words to use for finding pangrams
+const DEFAULT_WORDS: &str = include_str!("wordle_answers.txt");
 
-#[derive(Debug, PartialEq, Clone)]
-struct SanitizedString(String);
+/// Finds groups of words that together contain every letter of the alphabet
+#[derive(Debug, Parser)]
+#[command(version)]
+struct Args {
+    /// Word list to search ("-" reads from stdin).
+    /// Defaults to the embedded list of Wordle answers.
+    word_list: Option<PathBuf>,
 
-impl SanitizedString {
-    fn sanitize(string: &str) -> SanitizedString {
-        let output = string
-            .trim()
-            .to_uppercase()
-            .chars()
-            .filter(|c| c.is_ascii_alphabetic())
-            .collect();
+    /// Layout of the word list: text (one word per line), csv, tsv, json (an array of strings)
+    /// or frequency (a word and its count on each line) [default: from the file extension]
+    #[arg(long, value_name = "FORMAT")]
+    input_format: Option<WordListFormat>,
 
-        Self(output)
-    }
+    /// Column of a CSV or TSV word list holding the words: a number (from 1) or a header name
+    #[arg(long, value_name = "N|NAME", default_value = "1")]
+    column: Column,
 
-    fn get_unique_letters(&self) -> String {
-        let mut output: Vec<char> = self.0.chars().collect();
-        output.sort_by(|a, b| a.cmp(&b));
-        output.dedup();
-        return output.iter().collect::<String>()
-    }
-}
+    /// Read the first row of a CSV or TSV word list as a word rather than a header
+    #[arg(long)]
+    no_header: bool,
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Word {
-    name: String,
-    letters_present: u32
-}
+    /// What to do with words containing characters outside the alphabet: strip them
+    /// ("don't" becomes DONT), reject the word, split the word around them, or fail
+    /// with the line of the first such word
+    #[arg(long, value_name = "POLICY", default_value_t = NonLetters::Strip)]
+    non_letters: NonLetters,
 
-impl Word {
-    fn parse_string(s: &SanitizedString, order_of_letters: &Vec<char>) -> Word {
-        let mut letters_in_word = order_of_letters
-            .to_owned()
-            .into_iter()
-            .fold(0, |acc: u32, letter| (acc << 1) + s.0.contains(letter) as u32);
-        letters_in_word <<= 32 - order_of_letters.len();
-        return Word { name: s.0.to_owned(), letters_present: letters_in_word }
-    }
-}
+    /// Fold letters outside the alphabet into letters of it: remove accents (é becomes E)
+    /// and spell out ligatures (æ becomes AE)
+    #[arg(long)]
+    fold_diacritics: bool,
 
-#[derive(Debug)]
-struct WordsWithLetter {
-    words: Vec<Word>
-}
+    /// How to uppercase words: default, or turkic (i becomes İ and ı becomes I)
+    #[arg(long, value_name = "MAPPING", default_value_t = CaseMapping::Default)]
+    case_mapping: CaseMapping,
 
-impl WordsWithLetter {
-    fn new() -> WordsWithLetter {
-        return WordsWithLetter { words: vec![] }
-    }
-}
+    /// Leave out words with fewer letters than this
+    #[arg(long, value_name = "N", default_value_t = 1)]
+    min_length: usize,
+
+    /// Leave out words with more letters than this
+    #[arg(long, value_name = "N")]
+    max_length: Option<usize>,
+
+    /// Exact word length (e.g. 5), or a range (e.g. 4-7), instead of --min-length and --max-length
+    #[arg(long, value_name = "N|MIN-MAX", conflicts_with_all = ["min_length", "max_length"])]
+    length: Option<WordRange>,
+
+    /// Only search the first N words of the word list (the most frequent, for frequency lists)
+    /// that pass the other filters
+    #[arg(long, value_name = "N")]
+    most_frequent: Option<usize>,
+
+    /// Print how many lines of the word list were modified or dropped
+    #[arg(long)]
+    sanitize_report: bool,
+
+    /// Alphabet to cover: english, german, spanish, greek, russian, turkish,
+    /// or the letters of a custom alphabet (e.g. "ABCDEÉ")
+    #[arg(long, default_value = "english")]
+    alphabet: Alphabet,
+
+    /// Letters to cover instead of the whole alphabet, e.g. "A-M" or "STRINGER"
+    #[arg(long, value_name = "LETTERS")]
+    target: Option<String>,
+
+    /// Leave out words containing letters outside --target
+    #[arg(long, requires = "target")]
+    target_only: bool,
+
+    /// Search over the letters the word list contains when it
+    /// doesn't cover the whole alphabet, instead of failing
+    #[arg(long)]
+    allow_missing_letters: bool,
+
+    /// Also report near-pangrams missing up to this many letters
+    #[arg(long, default_value_t = 0)]
+    max_missing: usize,
+
+    /// Only allow pangrams whose words share no letters
+    #[arg(long)]
+    disjoint: bool,
+
+    /// Leave out words that repeat a letter
+    #[arg(long)]
+    distinct_letters: bool,
+
+    /// Only report pangrams where every word is needed to cover the letters
+    #[arg(long, conflicts_with = "reducible")]
+    irreducible: bool,
+
+    /// Only report pangrams containing a word the other words already cover (all of them, up to
+    /// --max-words)
+    #[arg(long)]
+    reducible: bool,
+
+    /// Only report pangrams containing this word (can be repeated)
+    #[arg(long, value_name = "WORD")]
+    require: Vec<String>,
+
+    /// Leave this word out of the search (can be repeated)
+    #[arg(long, value_name = "WORD")]
+    exclude: Vec<String>,
+
+    /// Only report pangrams whose words meet this constraint (can be repeated), e.g.
+    /// some:starts-with=Q, no:contains=XZ, every:ends-with=S or some:letter-at=2,A
+    #[arg(long, value_name = "CONSTRAINT")]
+    constraint: Vec<Constraint>,
+
+    /// Maximum number of words to use for finding pangrams
+    #[arg(long, default_value_t = PangramFinder::DEFAULT_MAX_WORDS)]
+    max_words: usize,
+
+    /// Minimum number of words a reported pangram must use
+    #[arg(long, default_value_t = 1)]
+    min_words: usize,
+
+    /// Exact number of words (e.g. 5), or a range (e.g. 4-6), instead of --min-words and --max-words
+    #[arg(long, value_name = "N|MIN-MAX", conflicts_with_all = ["min_words", "max_words"])]
+    words: Option<WordRange>,
+
+    /// Sort pangrams by words, length (total letters), overlap (letters shared between words)
+    /// or rank (position of the words in the word list)
+    #[arg(long, value_name = "METRIC", default_value_t = SortBy::Words)]
+    sort_by: SortBy,
+
+    /// Only print the best K pangrams by the --sort-by metric
+    #[arg(long, value_name = "K")]
+    top: Option<usize>,
+
+    /// Only find the pangrams with the fewest total letters
+    #[arg(long, conflicts_with = "first")]
+    shortest: bool,
 
-#[derive(Debug)]
-struct SearchStructure {
-    search_structure: Vec<WordsWithLetter>
+    /// Number of threads to search with [default: number of CPUs]
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// Stop after the first N pangrams, printing them in the order they are found
+    #[arg(long, value_name = "N", conflicts_with_all = ["top", "sort_by"])]
+    first: Option<usize>,
+
+    /// Only print the number of pangrams found of each size
+    #[arg(long, conflicts_with = "list")]
+    count_only: bool,
+
+    /// Print every pangram found, one per line (the default)
+    #[arg(long)]
+    list: bool,
+
+    /// Output format: text, json, csv or ndjson (one JSON object per line)
+    #[arg(long, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
 }
 
-impl SearchStructure {
-    fn build(number_of_letters: usize, words: Vec<Word>) -> SearchStructure {
-        let mut output = vec![];
-        for _letter in 0..number_of_letters {
-            output.push(WordsWithLetter::new())
+impl Args {
+    fn read_word_list(&self) -> Result<Vec<WordListEntry>, Error> {
+        let format = self.input_format();
+        match &self.word_list {
+            None => format.parse_bytes(DEFAULT_WORDS.as_bytes(), &self.column, false),
+            Some(path) if path.as_os_str() == "-" => {
+                let mut bytes = vec![];
+                io::stdin().read_to_end(&mut bytes).map_err(|error| Error::io(None, error))?;
+                format.parse_bytes(&bytes, &self.column, !self.no_header)
+            },
+            Some(path) => format.read(path, &self.column, !self.no_header)
         }
+    }
 
-        for word in words {
-            let mut letters_remaining = word.letters_present;
-            while letters_remaining != 0 {
-                let next_letter_index = letters_remaining.leading_zeros() as usize;
-                output[next_letter_index].words.push(word.clone());
-                letters_remaining -= 1 << (31 - next_letter_index)
-            }
+    fn input_format(&self) -> WordListFormat {
+        match (&self.input_format, &self.word_list) {
+            (Some(format), _) => *format,
+            (None, Some(path)) => WordListFormat::from_path(path),
+            (None, None) => WordListFormat::Text
         }
+    }
 
-        return SearchStructure { search_structure: output }
+    fn minimality(&self) -> Minimality {
+        match (self.irreducible, self.reducible) {
+            (true, _) => Minimality::Irreducible,
+            (_, true) => Minimality::Reducible,
+            _ => Minimality::All
+        }
     }
 
-    fn find_pangrams(&self, current_pangram: Pangram, mut pangrams: Vec<Solution>) -> Vec<Solution> {
-        for new_word in &self.search_structure[current_pangram.next_missing_letter()].words {
-            match current_pangram.check_with(new_word.clone()) {
-                PangramState::CompletePangram(solution) => {
-                    pangrams.push(solution);
-                    continue
+    // Expands ranges like A-M to the letters between them in the alphabet
+    fn target(&self) -> Option<Result<Alphabet, AlphabetError>> {
+        let target: Vec<char> = self.target.as_ref()?.to_uppercase().chars().collect();
+        let letters = self.alphabet.letters();
+        let position = |letter: &char| letters.iter().position(|l| l == letter);
+        let mut output = vec![];
+        let mut i = 0;
+        while i < target.len() {
+            match (position(&target[i]), target.get(i + 1), target.get(i + 2).and_then(position)) {
+                (Some(start), Some('-'), Some(end)) if start <= end => {
+                    output.extend_from_slice(&letters[start..=end]);
+                    i += 3
                 },
-                PangramState::FailedPangram() => continue,
-                PangramState::PotentialPangram(potential_solution) => {
-                    pangrams = self.find_pangrams(potential_solution, pangrams)
+                _ => {
+                    output.push(target[i]);
+                    i += 1
                 }
             }
         }
-        return pangrams
+        Some(Alphabet::new(output))
+    }
+
+    fn word_lengths(&self) -> RangeInclusive<usize> {
+        match self.length {
+            Some(length) => length.min..=length.max,
+            None => self.min_length..=self.max_length.unwrap_or(usize::MAX)
+        }
+    }
+
+    fn word_range(&self) -> WordRange {
+        self.words.unwrap_or(WordRange { min: self.min_words, max: self.max_words })
     }
 }
 
-#[derive(Debug, PartialEq)]
-struct Pangram {
-    // Pangram, in this context, refers to a group of Words that captures one of each letter
-    // Trivial Example: [ABCDE, FGHIJ, KLMNO, PQRST, UVWXYZ]
-    selected_words: Vec<Word>,
-    selected_letters: u32
+#[derive(Debug, Clone, Copy)]
+struct WordRange {
+    min: usize,
+    max: usize
 }
 
-impl Pangram {
-    fn new() -> Pangram {
-        return Pangram { selected_words: vec![], selected_letters: 0 }
-    }
+impl FromStr for WordRange {
+    type Err = ParseIntError;
 
-    fn check_with(&self, new_word: Word) -> PangramState {
-        let new_selected_letters = self.selected_letters | new_word.letters_present;
-        if new_selected_letters.leading_ones() >= 26 {
-            let new_selected_words = &mut vec![new_word.clone()];
-            new_selected_words.extend_from_slice(&self.selected_words);
-            new_selected_words.sort_by(|a, b| a.letters_present.cmp(&b.letters_present));
-            return PangramState::CompletePangram(Solution { words: new_selected_words.to_vec() })
-        } else if self.selected_words.len() + 1 >= MAX_SOLUTION_SIZE {
-            return PangramState::FailedPangram()
-        } else {
-            let new_selected_words = &mut vec![new_word.clone()];
-            new_selected_words.extend_from_slice(&self.selected_words);
-            let new_pangram = Pangram { selected_words: new_selected_words.to_vec(), selected_letters: new_selected_letters };
-            return PangramState::PotentialPangram(new_pangram)
+    fn from_str(s: &str) -> Result<WordRange, ParseIntError> {
+        match s.split_once('-') {
+            Some((min, max)) => Ok(WordRange { min: min.trim().parse()?, max: max.trim().parse()? }),
+            None => s.trim().parse().map(|words| WordRange { min: words, max: words })
         }
     }
+}
 
-    fn next_missing_letter(&self) -> usize {
-        return self.selected_letters.leading_ones() as usize
-    }
+// Exit codes follow sysexits.h: 64 for bad usage, 65 for bad input data and 74 for I/O errors
+fn exit_on_error<T>(result: Result<T, Error>) -> T {
+    result.unwrap_or_else(|error| {
+        eprintln!("error: {error}");
+        process::exit(match error {
+            Error::Io { .. } => 74,
+            Error::InvalidEncoding { .. }
+            | Error::WordList(_)
+            | Error::EmptyWordList
+            | Error::InvalidWord { .. }
+            | Error::AlphabetNotCovered { .. } => 65,
+            Error::SolutionSizeOutOfRange { .. } | Error::TooManyConstraints { .. } => 64
+        })
+    })
 }
 
-#[derive(Debug)]
-enum PangramState {
-    PotentialPangram(Pangram),
-    FailedPangram(),
-    CompletePangram(Solution)
+fn warn_about_missing_letters(finder: &PangramFinder) {
+    let missing_letters = finder.missing_letters();
+    if !missing_letters.is_empty() {
+        eprintln!("warning: no word contains {}; every pangram will be missing these letters",
+                  missing_letters.iter().join(", "));
+    }
 }
 
-#[derive(Debug, PartialEq, Clone, Eq, Hash)]
-struct Solution {
-    words: Vec<Word>
+fn exit_on_write_error<T>(result: io::Result<T>) -> T {
+    result.unwrap_or_else(|error| {
+        // Stop quietly when the reader goes away, e.g. when piped into head
+        if error.kind() != io::ErrorKind::BrokenPipe {
+            eprintln!("error: could not write output: {error}");
+        }
+        process::exit(1)
+    })
 }
 
-fn main() -> () {
-    let mut sanitized_strings: Vec<SanitizedString> = ALL_WORDS
-        .split("\n")
-        .map(SanitizedString::sanitize)
-        .filter(|line| line.0.len() > 0)
-        .collect();
-    sanitized_strings.sort_by(|s1, s2| s1.0.cmp(&s2.0));
-    sanitized_strings.dedup();
-
-    let occurences_of_each_letter: HashMap<char, u32> = sanitized_strings
-        .iter()
-        .map(|s| s.get_unique_letters())
-        .collect::<String>()
-        .chars()
-        .fold(HashMap::new(), |mut map, letter| {
-            *map.entry(letter).or_insert(0) += 1;
-            map
-        });
-
-    let mut letters_sorted_by_rarity: Vec<char> =
-        occurences_of_each_letter.keys().copied().collect();
-    letters_sorted_by_rarity
-        .sort_by(|a, b| occurences_of_each_letter[a].cmp(&occurences_of_each_letter[b]));
-
-    let word_list: Vec<Word> = sanitized_strings
-        .iter()
-        .map(|s| Word::parse_string(s, &letters_sorted_by_rarity))
-        .collect();
-    
-    let search_structure = SearchStructure::build(letters_sorted_by_rarity.len(),
-                                                  word_list);
-    let all_pangrams = search_structure.find_pangrams(Pangram::new(), vec![]);
-    let no_dupes = all_pangrams.into_iter().unique();
-    
-    println!("{:?}", no_dupes.collect::<Vec<Solution>>().len());
+fn main() {
+    let args = Args::parse();
+    let all_words = exit_on_error(args.read_word_list());
+
+    let word_range = args.word_range();
+    let finder = PangramFinder::from_word_list(all_words)
+        .alphabet(args.alphabet.clone())
+        .non_letters(args.non_letters)
+        .fold_diacritics(args.fold_diacritics)
+        .case_mapping(args.case_mapping)
+        .word_lengths(args.word_lengths())
+        .require_words(&args.require)
+        .exclude_words(&args.exclude)
+        .constraints(args.constraint.iter().cloned())
+        .only_target_letters(args.target_only)
+        .allow_missing_letters(args.allow_missing_letters)
+        .max_missing_letters(args.max_missing)
+        .disjoint(args.disjoint)
+        .distinct_letters(args.distinct_letters)
+        .minimality(args.minimality())
+        .words(word_range.min..=word_range.max)
+        .sort_by(args.sort_by)
+        .threads(args.threads.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get())));
+
+    let finder = match args.target() {
+        Some(Ok(target)) => finder.target(target),
+        Some(Err(error)) => {
+            eprintln!("error: invalid --target: {error}");
+            process::exit(64)
+        },
+        None => finder
+    };
+    let finder = match args.most_frequent {
+        Some(most_frequent) => finder.most_frequent(most_frequent),
+        None => finder
+    };
+    let finder = match args.top {
+        Some(top) => finder.top(top),
+        None => finder
+    };
+
+    if args.sanitize_report {
+        eprintln!("{}", finder.sanitize_report());
+    }
+
+    let format = args.format;
+    if args.count_only && args.first.is_none() && args.top.is_none() && !args.shortest {
+        let counts = exit_on_error(finder.count());
+        warn_about_missing_letters(&finder);
+        let summary = Summary { counts, words_searched: finder.number_of_words() };
+        exit_on_write_error(summary.write(io::stdout().lock(), format));
+        return
+    }
+
+    // With --first, pangrams are printed as the search finds them rather than sorted at the end
+    let solutions: Box<dyn Iterator<Item = Solution>> = match args.first {
+        Some(first) => Box::new(exit_on_error(finder.solutions()).take(first)),
+        None if args.shortest => Box::new(exit_on_error(finder.find_shortest()).into_iter()),
+        None => Box::new(exit_on_error(finder.find()).into_iter())
+    };
+    warn_about_missing_letters(&finder);
+
+    let mut counts = PangramCounts::new(word_range.min..=finder.max_solution_size());
+    let mut writer = (!args.count_only).then(|| {
+        exit_on_write_error(SolutionWriter::new(io::stdout().lock(), format)).show_metric(args.sort_by)
+    });
+    for solution in solutions {
+        if let Some(writer) = &mut writer {
+            exit_on_write_error(writer.write(&solution));
+        }
+        counts.add(solution.len());
+    }
+
+    let summary = Summary { counts, words_searched: finder.number_of_words() };
+    match writer {
+        Some(writer) => exit_on_write_error(writer.finish(&summary).map(|_| ())),
+        None => exit_on_write_error(summary.write(io::stdout().lock(), format))
+    }
 }
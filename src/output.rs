@@ -0,0 +1,269 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::counts::PangramCounts;
+use crate::score::SortBy;
+use crate::solution::Solution;
+
+/// How solutions and summaries are written out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// One solution per line, then a table of counts by size
+    #[default]
+    Text,
+    /// A single JSON object with a `solutions` array and a `summary`
+    Json,
+    /// A header row, then one row per solution, with the words separated by spaces
+    Csv,
+    /// One JSON object per solution per line, then a line holding the `summary`
+    Ndjson
+}
+
+impl FromStr for OutputFormat {
+    type Err = ParseOutputFormatError;
+
+    fn from_str(s: &str) -> Result<OutputFormat, ParseOutputFormatError> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            "ndjson" | "jsonl" => Ok(OutputFormat::Ndjson),
+            _ => Err(ParseOutputFormatError(s.to_owned()))
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Ndjson => "ndjson"
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The error returned when parsing an unknown [`OutputFormat`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOutputFormatError(String);
+
+impl fmt::Display for ParseOutputFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown output format \"{}\" (expected text, json, csv or ndjson)", self.0)
+    }
+}
+
+impl Error for ParseOutputFormatError {}
+
+/// The totals of a whole run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    /// The number of pangrams found of each size
+    pub counts: PangramCounts,
+    /// The number of distinct words searched
+    pub words_searched: usize
+}
+
+impl Summary {
+    /// Writes the summary on its own, for runs that only count pangrams. Text and CSV are a table
+    /// of counts by size; JSON and NDJSON are an object holding the `summary`.
+    pub fn write<W: Write>(&self, mut out: W, format: OutputFormat) -> io::Result<()> {
+        match format {
+            OutputFormat::Text => self.write_table(&mut out),
+            OutputFormat::Json | OutputFormat::Ndjson => {
+                serde_json::to_writer(&mut out, &SummaryObject { summary: SummaryRecord::new(self) })?;
+                writeln!(out)
+            },
+            OutputFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(out);
+                for (size, count) in self.counts.by_size() {
+                    writer.serialize(SizeCount { size, count })?;
+                }
+                writer.flush()
+            }
+        }
+    }
+
+    fn write_table<W: Write>(&self, mut out: W) -> io::Result<()> {
+        for (size, count) in self.counts.by_size() {
+            writeln!(out, "n={size}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+// Field names are part of the JSON, CSV and NDJSON formats, so they mustn't change
+#[derive(Serialize)]
+struct SolutionRecord<'a> {
+    words: Vec<&'a str>,
+    size: usize,
+    letters_covered: usize,
+    overlap: usize,
+    length: usize,
+    rank: usize,
+    missing_letters: &'a [char]
+}
+
+impl<'a> SolutionRecord<'a> {
+    fn new(solution: &'a Solution) -> SolutionRecord<'a> {
+        let score = solution.score();
+        SolutionRecord {
+            words: solution.words().iter().map(|word| word.name()).collect(),
+            size: score.words,
+            letters_covered: score.letters_covered,
+            overlap: score.overlap,
+            length: score.length,
+            rank: score.rank,
+            missing_letters: solution.missing_letters()
+        }
+    }
+}
+
+// CSV has no lists, so the words are separated by spaces and the missing letters run together
+#[derive(Serialize)]
+struct CsvRecord {
+    words: String,
+    size: usize,
+    letters_covered: usize,
+    overlap: usize,
+    length: usize,
+    rank: usize,
+    missing_letters: String
+}
+
+impl CsvRecord {
+    fn new(solution: &Solution) -> CsvRecord {
+        let record = SolutionRecord::new(solution);
+        CsvRecord {
+            words: record.words.join(" "),
+            size: record.size,
+            letters_covered: record.letters_covered,
+            overlap: record.overlap,
+            length: record.length,
+            rank: record.rank,
+            missing_letters: record.missing_letters.iter().collect()
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SummaryRecord {
+    pangrams: u64,
+    min_words: usize,
+    max_words: usize,
+    words_searched: usize,
+    by_size: Vec<SizeCount>
+}
+
+impl SummaryRecord {
+    fn new(summary: &Summary) -> SummaryRecord {
+        SummaryRecord {
+            pangrams: summary.counts.total(),
+            min_words: summary.counts.min_words(),
+            max_words: summary.counts.max_words(),
+            words_searched: summary.words_searched,
+            by_size: summary.counts.by_size().map(|(size, count)| SizeCount { size, count }).collect()
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SizeCount {
+    size: usize,
+    count: u64
+}
+
+#[derive(Serialize)]
+struct SummaryObject {
+    summary: SummaryRecord
+}
+
+/// Writes solutions one at a time as they are found, then the run [`Summary`]
+#[derive(Debug)]
+pub struct SolutionWriter<W: Write> {
+    out: W,
+    format: OutputFormat,
+    metric: Option<SortBy>,
+    solutions_written: usize
+}
+
+impl<W: Write> SolutionWriter<W> {
+    /// Starts writing solutions in this format
+    pub fn new(mut out: W, format: OutputFormat) -> io::Result<SolutionWriter<W>> {
+        match format {
+            OutputFormat::Json => write!(out, "{{\"solutions\":[")?,
+            OutputFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(&mut out);
+                writer.write_record(["words", "size", "letters_covered", "overlap", "length", "rank", "missing_letters"])?;
+                writer.flush()?
+            },
+            OutputFormat::Text | OutputFormat::Ndjson => ()
+        }
+        Ok(SolutionWriter { out, format, metric: None, solutions_written: 0 })
+    }
+
+    /// Follows each solution in the text format with its value of this metric, e.g. `[rank 10]`
+    pub fn show_metric(mut self, metric: SortBy) -> SolutionWriter<W> {
+        self.metric = Some(metric);
+        self
+    }
+
+    /// Writes one solution
+    pub fn write(&mut self, solution: &Solution) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Text => match self.metric {
+                None | Some(SortBy::Words) => writeln!(self.out, "{solution}")?,
+                Some(metric) => writeln!(self.out, "{solution} [{metric} {}]", metric.key(&solution.score()))?
+            },
+            OutputFormat::Json => {
+                if self.solutions_written > 0 {
+                    write!(self.out, ",")?;
+                }
+                serde_json::to_writer(&mut self.out, &SolutionRecord::new(solution))?
+            },
+            OutputFormat::Csv => {
+                let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(&mut self.out);
+                writer.serialize(CsvRecord::new(solution))?;
+                writer.flush()?
+            },
+            OutputFormat::Ndjson => {
+                serde_json::to_writer(&mut self.out, &SolutionRecord::new(solution))?;
+                writeln!(self.out)?
+            }
+        }
+        self.solutions_written += 1;
+        Ok(())
+    }
+
+    /// Writes the summary after the solutions. CSV output has no summary, as it would not fit
+    /// the columns of the solutions.
+    pub fn finish(mut self, summary: &Summary) -> io::Result<W> {
+        match self.format {
+            OutputFormat::Text => {
+                writeln!(self.out)?;
+                summary.write_table(&mut self.out)?;
+                writeln!(self.out,
+                         "Found {} pangram(s) of {} to {} words from {} words",
+                         summary.counts.total(),
+                         summary.counts.min_words(),
+                         summary.counts.max_words(),
+                         summary.words_searched)?
+            },
+            OutputFormat::Json => {
+                write!(self.out, "],\"summary\":")?;
+                serde_json::to_writer(&mut self.out, &SummaryRecord::new(summary))?;
+                writeln!(self.out, "}}")?
+            },
+            OutputFormat::Csv => (),
+            OutputFormat::Ndjson => summary.write(&mut self.out, OutputFormat::Ndjson)?
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
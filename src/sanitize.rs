@@ -0,0 +1,286 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::alphabet::Alphabet;
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct SanitizedString(pub(crate) String);
+
+// How letters are uppercased and matched against the alphabet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct Normalization {
+    pub(crate) case_mapping: CaseMapping,
+    pub(crate) fold_diacritics: bool
+}
+
+impl Normalization {
+    fn apply(&self, string: &str, alphabet: &Alphabet) -> String {
+        self.fold(self.uppercase(string), alphabet)
+    }
+
+    // Composes accents with their letters first, so É matches É whichever way it was typed
+    fn uppercase(&self, string: &str) -> String {
+        let string: String = string.trim().nfc().collect();
+        match self.case_mapping {
+            CaseMapping::Default => string.to_uppercase(),
+            CaseMapping::Turkic => string
+                .chars()
+                .map(|c| match c {
+                    'i' => 'İ',
+                    'ı' => 'I',
+                    c => c
+                })
+                .collect::<String>()
+                .to_uppercase()
+        }
+    }
+
+    fn fold(&self, uppercase: String, alphabet: &Alphabet) -> String {
+        if self.fold_diacritics {
+            uppercase.chars().fold(String::new(), |mut output, letter| {
+                fold_letter(letter, alphabet, &mut output);
+                output
+            })
+        } else {
+            uppercase
+        }
+    }
+}
+
+// Letters of the alphabet are kept as they are, so folding never turns Ä into A for German
+fn fold_letter(letter: char, alphabet: &Alphabet, output: &mut String) {
+    if alphabet.contains(letter) {
+        output.push(letter);
+        return
+    }
+    // Letters that don't decompose into a base letter and accents
+    match letter {
+        'Æ' => output.push_str("AE"),
+        'Œ' => output.push_str("OE"),
+        'Ø' => output.push('O'),
+        'Đ' | 'Ð' => output.push('D'),
+        'Ł' => output.push('L'),
+        'Þ' => output.push_str("TH"),
+        'ẞ' => output.push_str("SS"),
+        _ => output.extend(letter.nfd().filter(|&c| !is_combining_mark(c)))
+    }
+}
+
+// What became of one line of the word list
+pub(crate) enum Sanitized {
+    Unchanged(SanitizedString),
+    Folded(SanitizedString),
+    Stripped(SanitizedString),
+    Split(Vec<SanitizedString>),
+    Rejected,
+    Blank
+}
+
+impl SanitizedString {
+    pub(crate) fn sanitize(string: &str, alphabet: &Alphabet, normalization: Normalization) -> SanitizedString {
+        let output = normalization
+            .apply(string, alphabet)
+            .chars()
+            .filter(|&c| alphabet.contains(c))
+            .collect();
+
+        Self(output)
+    }
+
+    pub(crate) fn sanitize_with(string: &str,
+                                alphabet: &Alphabet,
+                                normalization: Normalization,
+                                non_letters: NonLetters) -> Sanitized {
+        let unfolded = normalization.uppercase(string);
+        let uppercase = normalization.fold(unfolded.clone(), alphabet);
+        if uppercase.is_empty() {
+            return Sanitized::Blank
+        } else if uppercase == unfolded && uppercase.chars().all(|c| alphabet.contains(c)) {
+            return Sanitized::Unchanged(Self(uppercase))
+        } else if uppercase.chars().all(|c| alphabet.contains(c)) {
+            return Sanitized::Folded(Self(uppercase))
+        }
+
+        let mut words: Vec<SanitizedString> = match non_letters {
+            NonLetters::Strip => vec![Self(uppercase.chars().filter(|&c| alphabet.contains(c)).collect())],
+            NonLetters::Reject | NonLetters::Fail => return Sanitized::Rejected,
+            NonLetters::Split => uppercase
+                .split(|c| !alphabet.contains(c))
+                .map(|word| Self(word.to_owned()))
+                .collect()
+        };
+        words.retain(|word| !word.0.is_empty());
+
+        match words.len() {
+            0 => Sanitized::Blank,
+            1 => Sanitized::Stripped(words.remove(0)),
+            _ => Sanitized::Split(words)
+        }
+    }
+
+    pub(crate) fn get_unique_letters(&self) -> String {
+        let mut output: Vec<char> = self.0.chars().collect();
+        output.sort();
+        output.dedup();
+        output.iter().collect::<String>()
+    }
+
+    pub(crate) fn has_repeated_letters(&self) -> bool {
+        self.get_unique_letters().chars().count() < self.0.chars().count()
+    }
+}
+
+/// How to uppercase words before matching them against the alphabet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaseMapping {
+    /// The language-neutral Unicode mapping, where i becomes I
+    #[default]
+    Default,
+    /// Turkish and Azerbaijani, where i becomes İ and dotless ı becomes I
+    Turkic
+}
+
+impl FromStr for CaseMapping {
+    type Err = ParseCaseMappingError;
+
+    fn from_str(s: &str) -> Result<CaseMapping, ParseCaseMappingError> {
+        match s.to_lowercase().as_str() {
+            "default" => Ok(CaseMapping::Default),
+            "turkic" | "tr" | "az" => Ok(CaseMapping::Turkic),
+            _ => Err(ParseCaseMappingError(s.to_owned()))
+        }
+    }
+}
+
+impl fmt::Display for CaseMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            CaseMapping::Default => "default",
+            CaseMapping::Turkic => "turkic"
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The error returned when parsing an unknown [`CaseMapping`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCaseMappingError(String);
+
+impl fmt::Display for ParseCaseMappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown case mapping \"{}\" (expected default or turkic)", self.0)
+    }
+}
+
+impl Error for ParseCaseMappingError {}
+
+/// What to do with words that contain characters outside the alphabet, like the apostrophe
+/// in "don't". Words are always uppercased and trimmed of surrounding whitespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NonLetters {
+    /// Remove the characters, so "don't" becomes DONT
+    #[default]
+    Strip,
+    /// Leave the word out
+    Reject,
+    /// Split the word around the characters, so "x-ray" becomes X and RAY
+    Split,
+    /// Stop with [`Error::InvalidWord`](crate::Error::InvalidWord) at the first such word
+    Fail
+}
+
+impl FromStr for NonLetters {
+    type Err = ParseNonLettersError;
+
+    fn from_str(s: &str) -> Result<NonLetters, ParseNonLettersError> {
+        match s.to_lowercase().as_str() {
+            "strip" => Ok(NonLetters::Strip),
+            "reject" => Ok(NonLetters::Reject),
+            "split" => Ok(NonLetters::Split),
+            "fail" => Ok(NonLetters::Fail),
+            _ => Err(ParseNonLettersError(s.to_owned()))
+        }
+    }
+}
+
+impl fmt::Display for NonLetters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            NonLetters::Strip => "strip",
+            NonLetters::Reject => "reject",
+            NonLetters::Split => "split",
+            NonLetters::Fail => "fail"
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The error returned when parsing an unknown [`NonLetters`] policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNonLettersError(String);
+
+impl fmt::Display for ParseNonLettersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown policy \"{}\" (expected strip, reject, split or fail)", self.0)
+    }
+}
+
+impl Error for ParseNonLettersError {}
+
+/// How sanitizing the word list changed it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SanitizeReport {
+    /// Lines in the word list
+    pub lines: usize,
+    /// Lines kept as they were, apart from case and surrounding whitespace
+    pub unchanged: usize,
+    /// Lines whose letters were all folded into letters of the alphabet
+    pub folded: usize,
+    /// Lines that had characters outside the alphabet removed
+    pub stripped: usize,
+    /// Lines split into several words
+    pub split: usize,
+    /// Lines left out for containing characters outside the alphabet
+    pub rejected: usize,
+    /// Lines with no letters of the alphabet at all
+    pub blank: usize,
+    /// Words left out for being too short or too long
+    pub wrong_length: usize,
+    /// Words left out for appearing earlier in the list
+    pub duplicates: usize
+}
+
+impl SanitizeReport {
+    /// The number of lines that were folded, had characters removed or were split
+    pub fn modified(&self) -> usize {
+        self.folded + self.stripped + self.split
+    }
+
+    /// The number of lines and words left out
+    pub fn dropped(&self) -> usize {
+        self.rejected + self.blank + self.wrong_length + self.duplicates
+    }
+}
+
+impl fmt::Display for SanitizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f,
+               "{} lines: {} unchanged, {} modified ({} folded, {} stripped, {} split), {} dropped \
+                ({} rejected, {} blank, {} wrong length, {} duplicates)",
+               self.lines,
+               self.unchanged,
+               self.modified(),
+               self.folded,
+               self.stripped,
+               self.split,
+               self.dropped(),
+               self.rejected,
+               self.blank,
+               self.wrong_length,
+               self.duplicates)
+    }
+}
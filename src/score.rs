@@ -0,0 +1,84 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Measures of a [`Solution`](crate::Solution), for ranking solutions against each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Score {
+    /// The number of words
+    pub words: usize,
+    /// The total number of letters across all words
+    pub length: usize,
+    /// The number of distinct letters the words cover
+    pub letters_covered: usize,
+    /// How many times a letter appears in more than one word, e.g. 2 for a letter in three words
+    pub overlap: usize,
+    /// The sum of each word's position in the word list (starting from 0). For word lists
+    /// sorted by frequency, lower means more common words.
+    pub rank: usize
+}
+
+/// The metric to sort solutions by. Solutions are always sorted best first, with ties broken
+/// by number of words and then alphabetically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortBy {
+    /// Fewest words first
+    #[default]
+    Words,
+    /// Fewest total letters first
+    Length,
+    /// Least overlap between words first
+    Overlap,
+    /// Most common words first (lowest total rank)
+    Rank
+}
+
+impl SortBy {
+    /// The value of this metric for a score
+    pub fn key(&self, score: &Score) -> usize {
+        match self {
+            SortBy::Words => score.words,
+            SortBy::Length => score.length,
+            SortBy::Overlap => score.overlap,
+            SortBy::Rank => score.rank
+        }
+    }
+}
+
+impl FromStr for SortBy {
+    type Err = ParseSortByError;
+
+    fn from_str(s: &str) -> Result<SortBy, ParseSortByError> {
+        match s.to_lowercase().as_str() {
+            "words" => Ok(SortBy::Words),
+            "length" => Ok(SortBy::Length),
+            "overlap" => Ok(SortBy::Overlap),
+            "rank" | "commonness" => Ok(SortBy::Rank),
+            _ => Err(ParseSortByError(s.to_owned()))
+        }
+    }
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SortBy::Words => "words",
+            SortBy::Length => "length",
+            SortBy::Overlap => "overlap",
+            SortBy::Rank => "rank"
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// The error returned when parsing an unknown [`SortBy`] metric
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSortByError(String);
+
+impl fmt::Display for ParseSortByError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown metric \"{}\" (expected words, length, overlap or rank)", self.0)
+    }
+}
+
+impl Error for ParseSortByError {}
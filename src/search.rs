@@ -0,0 +1,679 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use itertools::Itertools;
+
+use crate::constraint::{all_constraints, constraints_met, Constraint, ConstraintMask, Predicate, Quantifier};
+use crate::solution::Solution;
+use crate::word::{letter_bit, LetterMask, Word};
+
+#[derive(Debug)]
+struct WordClass {
+    // Words with exactly the same set of letters (anagrams, or pairs like "SISSY" and "SYS").
+    // No pangram can use two words from a class, so the search picks classes instead of words.
+    // Words are also split by the constraints they meet, so a class meets constraints as a whole.
+    letters_present: LetterMask,
+    constraints_met: ConstraintMask,
+    words: Vec<Word>,
+    shortest_length: usize, // Length of the shortest word in the class
+    // Holds a word named by a some:word= constraint (a required word), so it can also be selected
+    // once the other classes cover every letter
+    required: bool
+}
+
+#[derive(Debug)]
+struct WordsWithLetter {
+    classes: Vec<usize>
+}
+
+impl WordsWithLetter {
+    fn new() -> WordsWithLetter {
+        WordsWithLetter { classes: vec![] }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SearchOptions {
+    pub(crate) letters_required: usize, // Number of letters (in search order) every pangram has to cover
+    pub(crate) max_solution_size: usize, // Maximum number of words to use for finding pangrams
+    pub(crate) max_missing_letters: usize, // Number of required letters a pangram may leave uncovered
+    pub(crate) disjoint: bool, // Whether words in a pangram may share letters
+    pub(crate) minimality: Minimality,
+    pub(crate) constraints: Vec<Constraint>
+}
+
+impl SearchOptions {
+    // The constraints the walk itself has to meet. Reducible pangrams are made by adding words to
+    // irreducible ones, so those only have to meet the constraints once the words are added.
+    fn constraints_to_meet(&self) -> ConstraintMask {
+        match self.minimality {
+            Minimality::Reducible => 0,
+            Minimality::All | Minimality::Irreducible => all_constraints(&self.constraints)
+        }
+    }
+
+    // The constraints that name a word every pangram must have. The search can still select that
+    // word once the other words cover every letter, so pangrams aren't lost when they already
+    // cover its letters. Reducible pangrams get it by adding words to irreducible ones instead.
+    // Only pangrams missing a constraint need it, so it's kept out of check_with, which is on the
+    // hot path.
+    #[inline(never)]
+    fn required_word_constraints(&self) -> ConstraintMask {
+        if self.minimality == Minimality::Reducible {
+            return 0
+        }
+        self.constraints
+            .iter()
+            .enumerate()
+            .filter(|(_, constraint)| {
+                constraint.quantifier() == Quantifier::SomeWord && matches!(constraint.predicate(), Predicate::Word(_))
+            })
+            .fold(0, |mask, (constraint_index, _)| mask | (1 << constraint_index))
+    }
+}
+
+/// Whether pangrams may include a word whose letters the other words already cover
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Minimality {
+    /// Report every pangram
+    #[default]
+    All,
+    /// Only report pangrams where removing any word leaves some letter uncovered
+    Irreducible,
+    /// Only report pangrams with at least one word that could be removed. Unlike [`Minimality::All`],
+    /// this includes the words the search never needs, as every word whose letters the others
+    /// already cover can be added.
+    Reducible
+}
+
+#[derive(Debug)]
+pub(crate) struct SearchStructure {
+    search_structure: Vec<WordsWithLetter>,
+    classes: Vec<WordClass>,
+    required_classes: Vec<usize>,
+    order_of_letters: Vec<char>,
+    options: SearchOptions
+}
+
+impl SearchStructure {
+    pub(crate) fn build(order_of_letters: &[char], words: Vec<Word>, options: SearchOptions) -> SearchStructure {
+        let mut classes: Vec<WordClass> = vec![];
+        let mut class_of_letters: HashMap<(LetterMask, ConstraintMask), usize> = HashMap::new();
+        let allowed_words = words
+            .into_iter()
+            .filter(|word| options.constraints.iter().all(|constraint| constraint.allows(&word.name)));
+        for word in allowed_words {
+            let constraints_met = constraints_met(&options.constraints, &word.name);
+            let class_index = *class_of_letters.entry((word.letters_present, constraints_met)).or_insert_with(|| {
+                classes.push(WordClass {
+                    letters_present: word.letters_present,
+                    constraints_met,
+                    words: vec![],
+                    shortest_length: usize::MAX,
+                    required: constraints_met & options.required_word_constraints() != 0
+                });
+                classes.len() - 1
+            });
+            let class = &mut classes[class_index];
+            class.shortest_length = class.shortest_length.min(word.name.chars().count());
+            class.words.push(word);
+        }
+        let mut output = vec![];
+        for _letter in order_of_letters {
+            output.push(WordsWithLetter::new())
+        }
+
+        for (class_index, class) in classes.iter().enumerate() {
+            let mut letters_remaining = class.letters_present;
+            while letters_remaining != 0 {
+                let next_letter_index = letters_remaining.leading_zeros() as usize;
+                output[next_letter_index].classes.push(class_index);
+                letters_remaining -= letter_bit(next_letter_index)
+            }
+        }
+
+        let required_classes = (0..classes.len()).filter(|&class_index| classes[class_index].required).collect();
+        SearchStructure {
+            search_structure: output,
+            classes,
+            required_classes,
+            order_of_letters: order_of_letters.to_vec(),
+            options
+        }
+    }
+
+    pub(crate) fn solutions(self: &Arc<Self>) -> Solutions {
+        Solutions::new(Pangrams::from_state(self.clone(), PangramState::Potential(Pangram::new())))
+    }
+
+    // Branch and bound: once a pangram of some length is found, branches that are already
+    // longer are abandoned. Returns every pangram (of at least min_solution_size words) that
+    // ties for the fewest total letters.
+    pub(crate) fn shortest_pangrams(self: &Arc<Self>, min_solution_size: usize) -> Vec<Solution> {
+        let mut pangrams = Pangrams::from_state(self.clone(), PangramState::Potential(Pangram::new()));
+        let mut shortest: Vec<Pangram> = vec![];
+        while let Some(pangram) = pangrams.next() {
+            if pangram.selected_classes.len() < min_solution_size {
+                continue
+            }
+            let length = pangram.shortest_length(&self.classes);
+            if pangrams.max_length.is_none_or(|max_length| length < max_length) {
+                pangrams.max_length = Some(length);
+                shortest.clear();
+            }
+            shortest.push(pangram);
+        }
+
+        shortest
+            .into_iter()
+            .flat_map(|pangram| self.to_solutions(pangram, true))
+            .collect()
+    }
+
+    // Searches each branch of the first (rarest) letter on its own thread. Results are returned
+    // in the same order as solutions(), whatever order the branches finish in.
+    pub(crate) fn find_pangrams_parallel(self: &Arc<Self>, threads: usize) -> Vec<Solution> {
+        self.search_branches_parallel(threads, |pangrams| Solutions::new(pangrams).collect::<Vec<Solution>>())
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    // Counts pangrams (indexed by number of words) without building any solutions: each
+    // pangram of word classes stands for as many solutions as there are ways to pick its words
+    pub(crate) fn count_pangrams(self: &Arc<Self>, threads: usize) -> Vec<u64> {
+        let count = |pangrams: Pangrams| {
+            let mut counts = vec![0; self.options.max_solution_size + 1];
+            for pangram in pangrams {
+                let multiplicity: u64 = pangram.selected_classes
+                    .iter()
+                    .map(|&class_index| self.classes[class_index].words.len() as u64)
+                    .product();
+                counts[pangram.selected_classes.len()] += multiplicity;
+            }
+            counts
+        };
+
+        if threads > 1 {
+            self.search_branches_parallel(threads, count)
+                .into_iter()
+                .reduce(|total, counts| total.iter().zip(counts).map(|(a, b)| a + b).collect())
+                .unwrap_or_else(|| vec![0; self.options.max_solution_size + 1])
+        } else {
+            count(Pangrams::from_state(self.clone(), PangramState::Potential(Pangram::new())))
+        }
+    }
+
+    fn search_branches_parallel<T, F>(self: &Arc<Self>, threads: usize, search_branch: F) -> Vec<T>
+    where
+        T: Send + Default,
+        F: Fn(Pangrams) -> T + Sync
+    {
+        let root = Pangram::new();
+        let branches: Vec<Mutex<Option<PangramState>>> = (0..)
+            .map_while(|branch_index| self.branch(&root, branch_index))
+            .map(|state| Mutex::new(Some(state)))
+            .collect();
+        let results: Vec<Mutex<T>> = branches.iter().map(|_| Mutex::new(T::default())).collect();
+        let next_branch = AtomicUsize::new(0);
+
+        thread::scope(|scope| {
+            for _thread in 0..threads.min(branches.len()) {
+                scope.spawn(|| loop {
+                    let branch_index = next_branch.fetch_add(1, Ordering::Relaxed);
+                    let Some(branch) = branches.get(branch_index) else { break };
+                    let state = branch.lock().unwrap().take().unwrap();
+                    *results[branch_index].lock().unwrap() = search_branch(Pangrams::from_state(self.clone(), state));
+                });
+            }
+        });
+
+        results
+            .into_iter()
+            .map(|result| result.into_inner().unwrap())
+            .collect()
+    }
+
+    // The ways to extend a pangram: one for each class containing its next missing letter, then
+    // skipping that letter. Once every letter is covered, they are the classes holding required
+    // words instead. Returns None once branch_index is past the last of them.
+    fn branch(&self, current_pangram: &Pangram, branch_index: usize) -> Option<PangramState> {
+        if current_pangram.covers(&self.options) {
+            let &class_index = self.required_classes.get(branch_index)?;
+            return if current_pangram.selected_classes.contains(&class_index) {
+                Some(PangramState::Failed())
+            } else {
+                Some(current_pangram.check_with(class_index, &self.classes[class_index], &self.options))
+            }
+        }
+
+        let classes = self.classes_containing_next_letter(current_pangram);
+        if let Some(&class_index) = classes.get(branch_index) {
+            Some(current_pangram.check_with(class_index, &self.classes[class_index], &self.options))
+        } else if branch_index == classes.len() && current_pangram.can_skip(1, &self.options) {
+            Some(current_pangram.skip(current_pangram.next_missing_letter(), &self.options))
+        } else {
+            None
+        }
+    }
+
+    fn classes_containing_next_letter(&self, current_pangram: &Pangram) -> &[usize] {
+        // A pangram that covers its letters but lacks a required word can only add required words
+        if current_pangram.selected_classes.len() < self.options.max_solution_size && !current_pangram.covers(&self.options) {
+            &self.search_structure[current_pangram.next_missing_letter()].classes
+        } else {
+            &[]
+        }
+    }
+
+    // The reducible pangrams made by adding classes to an irreducible one: any classes within the
+    // letters it covers, up to the maximum size. Each reducible pangram is made from exactly one
+    // irreducible one, the classes left by removing redundant classes from the last.
+    fn reducible_pangrams(&self, irreducible: &Pangram) -> Vec<Pangram> {
+        let options = &self.options;
+        let addable: Vec<usize> = (0..self.classes.len())
+            .filter(|class_index| !irreducible.selected_classes.contains(class_index))
+            .filter(|&class_index| {
+                let letters_present = self.classes[class_index].letters_present;
+                letters_present & irreducible.skipped_letters == 0
+                    && !(options.disjoint && letters_present & irreducible.selected_letters != 0)
+            })
+            .collect();
+        let mut irreducible_classes = irreducible.selected_classes.clone();
+        irreducible_classes.sort_unstable();
+
+        (1..=options.max_solution_size - irreducible.selected_classes.len())
+            .flat_map(|added| addable.iter().copied().combinations(added))
+            .filter_map(|added_classes| {
+                let mut pangram = irreducible.clone();
+                for class_index in added_classes {
+                    // Classes split by constraints can have the same letters, but no pangram
+                    // uses two words with the same letters
+                    let class = &self.classes[class_index];
+                    let has_same_letters = pangram.selected_classes
+                        .iter()
+                        .any(|&selected_class| self.classes[selected_class].letters_present == class.letters_present);
+                    if has_same_letters || (options.disjoint && class.letters_present & pangram.selected_letters != 0) {
+                        return None
+                    }
+                    pangram.selected_classes.push(class_index);
+                    pangram.selected_letters |= class.letters_present;
+                    pangram.constraints_met |= class.constraints_met;
+                }
+                let is_wanted = pangram.constraints_met == all_constraints(&options.constraints)
+                    && pangram.irreducible_classes(&self.classes, options) == irreducible_classes;
+                is_wanted.then_some(pangram)
+            })
+            .collect()
+    }
+
+    // Expands a pangram of word classes into every choice of one word from each class,
+    // or only the choices of the shortest words
+    fn to_solutions(&self, pangram: Pangram, only_shortest: bool) -> impl Iterator<Item = Solution> + '_ {
+        let missing_letters: Vec<char> = self.order_of_letters
+            .iter()
+            .enumerate()
+            .filter(|(letter_index, _)| pangram.skipped_letters & letter_bit(*letter_index) != 0)
+            .map(|(_, &letter)| letter)
+            .collect();
+
+        pangram.selected_classes
+            .iter()
+            .map(|&class_index| {
+                let class = &self.classes[class_index];
+                class.words
+                    .iter()
+                    .filter(move |word| !only_shortest || word.name.chars().count() == class.shortest_length)
+            })
+            .multi_cartesian_product()
+            .map(move |words| {
+                let mut words: Vec<Word> = words.into_iter().cloned().collect();
+                words.sort_by(|a, b| a.name.cmp(&b.name));
+                Solution { words, missing_letters: missing_letters.clone() }
+            })
+    }
+}
+
+/// Walks the search tree depth first, yielding each complete pangram of word classes as soon as
+/// it is found
+#[derive(Debug)]
+pub(crate) struct Pangrams {
+    search_structure: Arc<SearchStructure>,
+    start: Option<PangramState>, // The state the walk starts from, until it is followed
+    stack: Vec<(Pangram, usize)>, // Pangrams being extended, each with the next branch to try
+    max_length: Option<usize>, // Pangrams whose shortest words total more letters are abandoned
+    pending: VecDeque<Pangram> // Reducible pangrams made from the last irreducible one
+}
+
+impl Pangrams {
+    fn from_state(search_structure: Arc<SearchStructure>, state: PangramState) -> Pangrams {
+        Pangrams { search_structure, start: Some(state), stack: vec![], max_length: None, pending: VecDeque::new() }
+    }
+
+    fn is_too_long(&self, pangram: &Pangram) -> bool {
+        self.max_length
+            .is_some_and(|max_length| pangram.shortest_length(&self.search_structure.classes) > max_length)
+    }
+
+    // Finds the next branch, from the deepest pangram, that doesn't fail straight away
+    fn next_state(&mut self) -> Option<PangramState> {
+        if let Some(state) = self.start.take() {
+            return Some(state)
+        }
+
+        let search_structure = &*self.search_structure;
+        loop {
+            let (current_pangram, branch_index) = self.stack.last_mut()?;
+            // Same branches as SearchStructure::branch, without looking up the classes each time
+            let classes = search_structure.classes_containing_next_letter(current_pangram);
+            while let Some(&class_index) = classes.get(*branch_index) {
+                *branch_index += 1;
+                let state = current_pangram.check_with(class_index,
+                                                       &search_structure.classes[class_index],
+                                                       &search_structure.options);
+                if !matches!(state, PangramState::Failed()) {
+                    return Some(state)
+                }
+            }
+            if let Some(state) = search_structure.branch(current_pangram, *branch_index) {
+                *branch_index += 1;
+                return Some(state)
+            }
+            self.stack.pop();
+        }
+    }
+}
+
+impl Iterator for Pangrams {
+    type Item = Pangram;
+
+    fn next(&mut self) -> Option<Pangram> {
+        loop {
+            if let Some(pangram) = self.pending.pop_front() {
+                if !self.is_too_long(&pangram) {
+                    return Some(pangram)
+                }
+                continue
+            }
+
+            match self.next_state()? {
+                PangramState::Complete(pangram) => {
+                    let classes = &self.search_structure.classes;
+                    let options = &self.search_structure.options;
+                    // Reducible pangrams are made from the irreducible ones, which come first
+                    let is_reducible = pangram.has_redundant_class(classes, options);
+                    let wanted = options.minimality == Minimality::All || !is_reducible;
+                    if !wanted || self.is_too_long(&pangram) || !pangram.is_first_order(classes, options) {
+                        continue
+                    }
+                    if options.minimality != Minimality::Reducible {
+                        return Some(pangram)
+                    }
+                    self.pending.extend(self.search_structure.reducible_pangrams(&pangram));
+                },
+                PangramState::Failed() => (),
+                PangramState::Potential(potential_solution) => {
+                    // Adding words never makes a redundant word necessary again
+                    let options = &self.search_structure.options;
+                    let is_redundant = options.minimality != Minimality::All
+                        && potential_solution.has_redundant_class(&self.search_structure.classes, options);
+                    if !is_redundant && !self.is_too_long(&potential_solution) {
+                        self.stack.push((potential_solution, 0))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Expands each pangram of word classes into solutions as the walk finds them
+#[derive(Debug)]
+pub(crate) struct Solutions {
+    pangrams: Pangrams,
+    pending: VecDeque<Solution> // Solutions expanded from the last complete pangram
+}
+
+impl Solutions {
+    fn new(pangrams: Pangrams) -> Solutions {
+        Solutions { pangrams, pending: VecDeque::new() }
+    }
+}
+
+impl Iterator for Solutions {
+    type Item = Solution;
+
+    fn next(&mut self) -> Option<Solution> {
+        loop {
+            if let Some(solution) = self.pending.pop_front() {
+                return Some(solution)
+            }
+            let pangram = self.pangrams.next()?;
+            self.pending.extend(self.pangrams.search_structure.to_solutions(pangram, false));
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Pangram {
+    // Pangram, in this context, refers to a group of Words that captures one of each letter
+    // Trivial Example: [ABCDE, FGHIJ, KLMNO, PQRST, UVWXYZ]
+    selected_classes: Vec<usize>, // In the order they were selected
+    selected_letters: LetterMask,
+    // Letters the pangram has given up on; words containing them can no longer be selected,
+    // so every near-pangram is found exactly once with its true set of missing letters
+    skipped_letters: LetterMask,
+    constraints_met: ConstraintMask
+}
+
+impl Pangram {
+    pub(crate) fn new() -> Pangram {
+        Pangram {
+            selected_classes: vec![],
+            selected_letters: 0,
+            skipped_letters: 0,
+            constraints_met: 0
+        }
+    }
+
+    fn check_with(&self, class_index: usize, new_class: &WordClass, options: &SearchOptions) -> PangramState {
+        if new_class.letters_present & self.skipped_letters != 0 {
+            return PangramState::Failed()
+        }
+        if options.disjoint && new_class.letters_present & self.selected_letters != 0 {
+            return PangramState::Failed()
+        }
+
+        let new_selected_letters = self.selected_letters | new_class.letters_present;
+        let new_constraints_met = self.constraints_met | new_class.constraints_met;
+        let constraints_to_meet = options.constraints_to_meet();
+        let meets_constraints = new_constraints_met & constraints_to_meet == constraints_to_meet;
+        let is_complete = covers(new_selected_letters | self.skipped_letters, options);
+        // Once it covers every letter, a pangram can only go on to add the required words it lacks
+        if is_complete
+            && !meets_constraints
+            && (self.selected_classes.len() + 1 >= options.max_solution_size
+                || options.required_word_constraints() & !new_constraints_met == 0) {
+            return PangramState::Failed()
+        }
+        // Once it has run out of words, a pangram can only go on to skip its remaining letters
+        if !is_complete
+            && self.selected_classes.len() + 1 >= options.max_solution_size
+            && (!meets_constraints
+                || !self.can_skip(uncovered_letters(new_selected_letters | self.skipped_letters, options), options)) {
+            return PangramState::Failed()
+        }
+
+        let mut new_selected_classes = self.selected_classes.clone();
+        new_selected_classes.push(class_index);
+        let new_pangram = Pangram {
+            selected_classes: new_selected_classes,
+            selected_letters: new_selected_letters,
+            skipped_letters: self.skipped_letters,
+            constraints_met: new_constraints_met
+        };
+
+        if is_complete && meets_constraints {
+            PangramState::Complete(new_pangram)
+        } else {
+            PangramState::Potential(new_pangram)
+        }
+    }
+
+    fn skip(&self, letter_index: usize, options: &SearchOptions) -> PangramState {
+        let new_pangram = Pangram {
+            selected_classes: self.selected_classes.clone(),
+            selected_letters: self.selected_letters,
+            skipped_letters: self.skipped_letters | letter_bit(letter_index),
+            constraints_met: self.constraints_met
+        };
+
+        let is_complete = new_pangram.covers(options);
+        let meets_constraints = new_pangram.meets_constraints(options);
+        if !meets_constraints
+            && (is_complete || self.selected_classes.len() >= options.max_solution_size)
+            && !new_pangram.can_add_required_word(options) {
+            PangramState::Failed()
+        } else if !is_complete || !meets_constraints {
+            PangramState::Potential(new_pangram)
+        } else if new_pangram.selected_classes.is_empty() {
+            PangramState::Failed()
+        } else {
+            PangramState::Complete(new_pangram)
+        }
+    }
+
+    // Whether every letter is covered or skipped
+    fn covers(&self, options: &SearchOptions) -> bool {
+        covers(self.selected_letters | self.skipped_letters, options)
+    }
+
+    // Whether a required word is still missing and there's room to add it
+    fn can_add_required_word(&self, options: &SearchOptions) -> bool {
+        self.selected_classes.len() < options.max_solution_size
+            && options.required_word_constraints() & !self.constraints_met != 0
+    }
+
+    fn meets_constraints(&self, options: &SearchOptions) -> bool {
+        self.constraints_met & options.constraints_to_meet() == options.constraints_to_meet()
+    }
+
+    // Whether the classes were selected in the first order, by class index, that the search can
+    // select them in. A group of classes can often be reached in several orders; only the first
+    // is reported, so each group is found exactly once. Only complete pangrams are checked, so
+    // this is kept out of check_with, which is on the hot path.
+    #[inline(never)]
+    fn is_first_order(&self, classes: &[WordClass], options: &SearchOptions) -> bool {
+        // Missing letters are skipped as soon as the search reaches them
+        let mut covered = self.skipped_letters;
+        for (position, &class_index) in self.selected_classes.iter().enumerate() {
+            let remaining = &self.selected_classes[position..];
+            let has_earlier_order = remaining.iter().any(|&other| {
+                other < class_index
+                    && can_select_next(classes, options, covered, other)
+                    && can_select_in_some_order(classes, options, covered | classes[other].letters_present,
+                                                &without(remaining, other))
+            });
+            if has_earlier_order {
+                return false
+            }
+            covered |= classes[class_index].letters_present;
+        }
+        true
+    }
+
+    fn can_skip(&self, number_of_letters: usize, options: &SearchOptions) -> bool {
+        self.skipped_letters.count_ones() as usize + number_of_letters <= options.max_missing_letters
+    }
+
+    fn next_missing_letter(&self) -> usize {
+        (self.selected_letters | self.skipped_letters).leading_ones() as usize
+    }
+
+    // The fewest letters the pangram's words can total
+    fn shortest_length(&self, classes: &[WordClass]) -> usize {
+        self.selected_classes
+            .iter()
+            .map(|&class_index| classes[class_index].shortest_length)
+            .sum()
+    }
+
+    // The selected classes, in index order, left after removing redundant ones from the last
+    fn irreducible_classes(&self, classes: &[WordClass], options: &SearchOptions) -> Vec<usize> {
+        let mut kept = self.selected_classes.clone();
+        kept.sort_unstable();
+        for position in (0..kept.len()).rev() {
+            let covered_by_others = kept
+                .iter()
+                .enumerate()
+                .filter(|&(other_position, _)| other_position != position)
+                .fold(0, |letters, (_, &class_index)| letters | classes[class_index].letters_present);
+            if classes[kept[position]].letters_present & required_letters(options) & !covered_by_others == 0 {
+                kept.remove(position);
+            }
+        }
+        kept
+    }
+
+    // Whether some selected class only has letters that other classes also cover
+    fn has_redundant_class(&self, classes: &[WordClass], options: &SearchOptions) -> bool {
+        let mut covered_once: LetterMask = 0;
+        let mut covered_twice: LetterMask = 0;
+        for &class_index in &self.selected_classes {
+            let letters_present = classes[class_index].letters_present;
+            covered_twice |= covered_once & letters_present;
+            covered_once |= letters_present;
+        }
+
+        self.selected_classes
+            .iter()
+            .any(|&class_index| classes[class_index].letters_present & required_letters(options) & !covered_twice == 0)
+    }
+}
+
+// Whether the search can select the class once these letters are covered: it has to contain the
+// first letter not yet covered, or hold a required word once every letter is covered
+fn can_select_next(classes: &[WordClass], options: &SearchOptions, covered: LetterMask, class_index: usize) -> bool {
+    let class = &classes[class_index];
+    if covers(covered, options) {
+        class.required
+    } else {
+        class.letters_present & letter_bit(covered.leading_ones() as usize) != 0
+    }
+}
+
+// Whether the search can go on from the covered letters to select exactly these classes
+fn can_select_in_some_order(classes: &[WordClass], options: &SearchOptions, covered: LetterMask, remaining: &[usize]) -> bool {
+    if remaining.is_empty() {
+        return true
+    }
+    remaining.iter().any(|&class_index| {
+        can_select_next(classes, options, covered, class_index)
+            && can_select_in_some_order(classes, options, covered | classes[class_index].letters_present,
+                                        &without(remaining, class_index))
+    })
+}
+
+fn without(classes: &[usize], class_index: usize) -> Vec<usize> {
+    classes.iter().copied().filter(|&other| other != class_index).collect()
+}
+
+fn covers(letters: LetterMask, options: &SearchOptions) -> bool {
+    letters.leading_ones() as usize >= options.letters_required
+}
+
+fn required_letters(options: &SearchOptions) -> LetterMask {
+    !LetterMask::MAX.checked_shr(options.letters_required as u32).unwrap_or(0)
+}
+
+fn uncovered_letters(letters: LetterMask, options: &SearchOptions) -> usize {
+    (required_letters(options) & !letters).count_ones() as usize
+}
+
+#[derive(Debug)]
+enum PangramState {
+    Potential(Pangram),
+    Failed(),
+    Complete(Pangram)
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::score::Score;
+use crate::word::{LetterMask, Word};
+
+/// A group of words that together contain every letter of the alphabet,
+/// or all but a few of them when searching for near-pangrams
+#[derive(Debug, PartialEq, Clone, Eq, Hash, PartialOrd, Ord)]
+pub struct Solution {
+    // Words are kept in alphabetical order so equal solutions compare and print the same
+    pub(crate) words: Vec<Word>,
+    pub(crate) missing_letters: Vec<char>
+}
+
+impl Solution {
+    /// The words making up this solution, in alphabetical order
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    /// The letters of the alphabet that none of the words contain, in alphabet order
+    pub fn missing_letters(&self) -> &[char] {
+        &self.missing_letters
+    }
+
+    /// The number of words in this solution
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Whether this solution has no words (only possible for an empty alphabet)
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Measures this solution for ranking
+    pub fn score(&self) -> Score {
+        let letters_covered = self.words
+            .iter()
+            .fold(0 as LetterMask, |acc, word| acc | word.letters_present)
+            .count_ones() as usize;
+        let letters_per_word: usize = self.words
+            .iter()
+            .map(|word| word.letters_present.count_ones() as usize)
+            .sum();
+
+        Score {
+            words: self.words.len(),
+            length: self.words.iter().map(|word| word.name.chars().count()).sum(),
+            letters_covered,
+            overlap: letters_per_word - letters_covered,
+            rank: self.words.iter().map(|word| word.rank).sum()
+        }
+    }
+}
+
+impl fmt::Display for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.words.iter().map(|word| &word.name).join(" "))?;
+        if !self.missing_letters.is_empty() {
+            write!(f, " (missing {})", self.missing_letters.iter().join(", "))?;
+        }
+        Ok(())
+    }
+}
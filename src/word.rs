@@ -0,0 +1,37 @@
+use crate::sanitize::SanitizedString;
+
+// One bit per letter, with the first letter in the search order as the most significant bit
+pub(crate) type LetterMask = u128;
+
+pub(crate) fn letter_bit(letter_index: usize) -> LetterMask {
+    (1 << (LetterMask::BITS - 1)) >> letter_index
+}
+
+/// A single word from the word list, along with the letters it contains
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Word {
+    pub(crate) name: String,
+    pub(crate) letters_present: LetterMask,
+    pub(crate) rank: usize // Position in the word list, after empty and duplicate words are removed
+}
+
+impl Word {
+    pub(crate) fn parse_string(s: &SanitizedString, order_of_letters: &[char], rank: usize) -> Word {
+        let letters_in_word = order_of_letters
+            .iter()
+            .enumerate()
+            .filter(|(_, &letter)| s.0.contains(letter))
+            .fold(0, |acc, (letter_index, _)| acc | letter_bit(letter_index));
+        Word { name: s.0.to_owned(), letters_present: letters_in_word, rank }
+    }
+
+    /// The sanitized (uppercase) spelling of the word
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The position of the word in the word list, after empty and duplicate words are removed
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+}
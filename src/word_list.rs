@@ -0,0 +1,242 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde_json::value::RawValue;
+
+/// How the words in a word list are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WordListFormat {
+    /// One word per line
+    #[default]
+    Text,
+    /// Comma-separated values, with the words in one [`Column`]
+    Csv,
+    /// Tab-separated values, with the words in one [`Column`]
+    Tsv,
+    /// A JSON array of strings
+    Json,
+    /// One word per line, followed by how often it occurs (e.g. `the<TAB>23135851162`). Words are
+    /// ranked most frequent first, whatever order the lines are in.
+    Frequency
+}
+
+/// A word read from a word list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordListEntry {
+    /// The word as it appears in the word list
+    pub word: String,
+    /// The line the word is on, numbered from 1
+    pub line: usize
+}
+
+impl WordListFormat {
+    /// Guesses the format from a file extension (`.csv`, `.tsv`, `.json` or `.freq`), falling back
+    /// to plain text
+    pub fn from_path(path: &Path) -> WordListFormat {
+        let extension = path.extension().and_then(|extension| extension.to_str()).unwrap_or("");
+        match extension.to_lowercase().as_str() {
+            "csv" => WordListFormat::Csv,
+            "tsv" => WordListFormat::Tsv,
+            "json" => WordListFormat::Json,
+            "freq" => WordListFormat::Frequency,
+            _ => WordListFormat::Text
+        }
+    }
+
+    /// Reads the words out of a word list file. See [`WordListFormat::parse_bytes`].
+    pub fn read(&self, path: &Path, column: &Column, has_header: bool) -> Result<Vec<WordListEntry>, crate::Error> {
+        let bytes = fs::read(path).map_err(|error| crate::Error::io(Some(path.to_owned()), error))?;
+        self.parse_bytes(&bytes, column, has_header)
+    }
+
+    /// Reads the words out of a word list that should be UTF-8, reporting the first line that
+    /// isn't. A leading byte order mark is ignored.
+    pub fn parse_bytes(&self, bytes: &[u8], column: &Column, has_header: bool) -> Result<Vec<WordListEntry>, crate::Error> {
+        let input = std::str::from_utf8(bytes).map_err(|error| crate::Error::InvalidEncoding {
+            line: bytes[..error.valid_up_to()].iter().filter(|&&byte| byte == b'\n').count() + 1
+        })?;
+        Ok(self.parse(input.strip_prefix('\u{feff}').unwrap_or(input), column, has_header)?)
+    }
+
+    /// Reads the words out of a word list, in rank order. Only [`WordListFormat::Csv`] and
+    /// [`WordListFormat::Tsv`] use the column, and skip the first row when it is a header (which
+    /// it always is when the column is given by name).
+    pub fn parse(&self, input: &str, column: &Column, has_header: bool) -> Result<Vec<WordListEntry>, WordListError> {
+        match self {
+            WordListFormat::Text => Ok(input
+                .lines()
+                .enumerate()
+                .map(|(line_index, line)| WordListEntry { word: line.to_owned(), line: line_index + 1 })
+                .collect()),
+            WordListFormat::Csv => parse_table(input, b',', column, has_header),
+            WordListFormat::Tsv => parse_table(input, b'\t', column, has_header),
+            WordListFormat::Json => parse_json(input),
+            WordListFormat::Frequency => parse_frequency_list(input)
+        }
+    }
+}
+
+impl FromStr for WordListFormat {
+    type Err = ParseWordListFormatError;
+
+    fn from_str(s: &str) -> Result<WordListFormat, ParseWordListFormatError> {
+        match s.to_lowercase().as_str() {
+            "text" | "txt" => Ok(WordListFormat::Text),
+            "csv" => Ok(WordListFormat::Csv),
+            "tsv" => Ok(WordListFormat::Tsv),
+            "json" => Ok(WordListFormat::Json),
+            "frequency" | "freq" => Ok(WordListFormat::Frequency),
+            _ => Err(ParseWordListFormatError(s.to_owned()))
+        }
+    }
+}
+
+/// The column of a CSV or TSV word list that holds the words
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    /// The column at this position, starting from 0. The first row is skipped as a header unless
+    /// `has_header` is false when parsing (see [`WordListFormat::parse`]).
+    Index(usize),
+    /// The column with this name in the header row
+    Name(String)
+}
+
+impl Default for Column {
+    fn default() -> Column {
+        Column::Index(0)
+    }
+}
+
+impl FromStr for Column {
+    type Err = std::convert::Infallible;
+
+    /// Parses a column number (starting from 1) or a column name
+    fn from_str(s: &str) -> Result<Column, Self::Err> {
+        match s.trim().parse::<usize>() {
+            Ok(number) if number > 0 => Ok(Column::Index(number - 1)),
+            _ => Ok(Column::Name(s.to_owned()))
+        }
+    }
+}
+
+fn parse_table(input: &str, delimiter: u8, column: &Column, has_header: bool) -> Result<Vec<WordListEntry>, WordListError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(has_header || matches!(column, Column::Name(_)))
+        .flexible(true)
+        .from_reader(input.as_bytes());
+
+    let column_index = match column {
+        Column::Index(index) => *index,
+        Column::Name(name) => reader
+            .headers()
+            .map_err(table_error)?
+            .iter()
+            .position(|header| header.trim() == name)
+            .ok_or_else(|| WordListError::MissingColumn(name.to_owned()))?
+    };
+
+    reader
+        .records()
+        .map(|record| {
+            let record = record.map_err(table_error)?;
+            let line = record.position().map_or(0, |position| position.line() as usize);
+            match record.get(column_index) {
+                Some(word) => Ok(WordListEntry { word: word.to_owned(), line }),
+                None => Err(WordListError::Malformed { line, message: format!("no column {}", column_index + 1) })
+            }
+        })
+        .collect()
+}
+
+fn table_error(error: csv::Error) -> WordListError {
+    WordListError::Malformed {
+        line: error.position().map_or(0, |position| position.line() as usize),
+        message: error.to_string()
+    }
+}
+
+fn json_error(error: serde_json::Error) -> WordListError {
+    // The line is reported separately
+    let message = error.to_string();
+    let message = message.rsplit_once(" at line ").map_or(message.as_str(), |(message, _)| message);
+    WordListError::Malformed { line: error.line(), message: message.to_owned() }
+}
+
+fn parse_json(input: &str) -> Result<Vec<WordListEntry>, WordListError> {
+    // Parsing the strings first reports errors against the whole list; the raw values then give
+    // where each word starts
+    let words: Vec<String> = serde_json::from_str(input).map_err(json_error)?;
+    let raw_words: Vec<&RawValue> = serde_json::from_str(input).map_err(json_error)?;
+    let mut line = 1;
+    let mut offset = 0;
+    Ok(words
+        .into_iter()
+        .zip(raw_words)
+        .map(|(word, raw_word)| {
+            let word_offset = raw_word.get().as_ptr() as usize - input.as_ptr() as usize;
+            line += input[offset..word_offset].matches('\n').count();
+            offset = word_offset;
+            WordListEntry { word, line }
+        })
+        .collect())
+}
+
+fn parse_frequency_list(input: &str) -> Result<Vec<WordListEntry>, WordListError> {
+    let mut words_with_counts = vec![];
+    for (line_index, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue
+        }
+        let count = line
+            .trim_end()
+            .rsplit_once(char::is_whitespace)
+            .and_then(|(word, count)| Some((word.trim().to_owned(), count.parse::<u64>().ok()?)));
+        match count {
+            Some((word, count)) => words_with_counts.push((WordListEntry { word, line: line_index + 1 }, count)),
+            None => return Err(WordListError::Malformed {
+                line: line_index + 1,
+                message: format!("expected a word and a count, found \"{line}\"")
+            })
+        }
+    }
+
+    // The sort is stable, so words that occur equally often stay in file order
+    words_with_counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+    Ok(words_with_counts.into_iter().map(|(entry, _)| entry).collect())
+}
+
+/// Reasons a word list could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordListError {
+    /// The word list doesn't follow its format; lines are numbered from 1
+    Malformed { line: usize, message: String },
+    /// The header row has no column with this name
+    MissingColumn(String)
+}
+
+impl fmt::Display for WordListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordListError::Malformed { line, message } => write!(f, "line {line}: {message}"),
+            WordListError::MissingColumn(name) => write!(f, "no column named \"{name}\"")
+        }
+    }
+}
+
+impl Error for WordListError {}
+
+/// The error returned when parsing an unknown [`WordListFormat`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWordListFormatError(String);
+
+impl fmt::Display for ParseWordListFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown word list format \"{}\" (expected text, csv, tsv, json or frequency)", self.0)
+    }
+}
+
+impl Error for ParseWordListFormatError {}
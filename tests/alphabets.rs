@@ -0,0 +1,34 @@
+use pangram_finder::{Alphabet, AlphabetError, PangramFinder};
+
+#[test]
+fn presets_cover_their_own_letters() {
+    // Lowercase words are uppercased first, so the final sigma (ς) counts as Σ
+    let words = ["αβγδεζηθ", "ικλμνξοπ", "ρςτυφχψω", "αβγ"];
+    let solutions = PangramFinder::new(words).alphabet(Alphabet::greek()).find().unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "ΑΒΓΔΕΖΗΘ ΙΚΛΜΝΞΟΠ ΡΣΤΥΦΧΨΩ");
+}
+
+#[test]
+fn alphabets_can_have_up_to_128_letters() {
+    let letters: Vec<char> = ('\u{4E00}'..).take(100).collect();
+    let words: Vec<String> = letters.chunks(25).map(|chunk| chunk.iter().collect()).collect();
+    let alphabet = Alphabet::new(letters.iter().copied()).unwrap();
+    assert_eq!(alphabet.len(), 100);
+
+    let solutions = PangramFinder::new(&words).alphabet(alphabet.clone()).find().unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].len(), 4);
+
+    // Leaving out the last word leaves its letters, the last of the alphabet, uncovered
+    let solutions = PangramFinder::new(&words[..3])
+        .alphabet(alphabet)
+        .allow_missing_letters(true)
+        .find()
+        .unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].missing_letters(), &letters[75..]);
+
+    let too_many: Vec<char> = ('\u{4E00}'..).take(129).collect();
+    assert_eq!(Alphabet::new(too_many), Err(AlphabetError::TooManyLetters(129)));
+}
@@ -0,0 +1,26 @@
+// Fixtures shared by the integration tests; each test file uses only some of them
+#![allow(dead_code)]
+
+use pangram_finder::{Alphabet, PangramFinder, Solution};
+
+const WORDLE_ANSWERS: &str = include_str!("../../src/wordle_answers.txt");
+
+/// The first 300 Wordle answers, uppercased
+pub fn wordle_words() -> Vec<String> {
+    WORDLE_ANSWERS.lines().take(300).map(|word| word.to_uppercase()).collect()
+}
+
+/// A search over the first 300 Wordle answers for pangrams of A to P (and near-pangrams missing
+/// one letter) of up to four words: quick to run, with thousands of results
+pub fn wordle_finder() -> PangramFinder {
+    PangramFinder::new(wordle_words())
+        .alphabet("ABCDEFGHIJKLMNOP".parse::<Alphabet>().unwrap())
+        .max_words(4)
+        .max_missing_letters(1)
+}
+
+/// Solutions as text. Leaving words out of a search changes the ranks and letter order of the
+/// others, so solutions from different searches are compared this way.
+pub fn text<'a>(solutions: impl IntoIterator<Item = &'a Solution>) -> Vec<String> {
+    solutions.into_iter().map(|solution| solution.to_string()).collect()
+}
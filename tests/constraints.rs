@@ -0,0 +1,56 @@
+use pangram_finder::{Alphabet, Constraint, Minimality, PangramFinder, Quantifier, Solution};
+
+mod common;
+use common::text;
+
+fn meets(solution: &Solution, constraint: &Constraint) -> bool {
+    let mut matches = solution.words().iter().map(|word| constraint.predicate().matches(word.name()));
+    match constraint.quantifier() {
+        Quantifier::SomeWord => matches.any(|matched| matched),
+        Quantifier::EveryWord => matches.all(|matched| matched),
+        Quantifier::NoWord => !matches.any(|matched| matched)
+    }
+}
+
+#[test]
+fn constraints_match_filtering_all_pangrams() {
+    let constraint_sets = [
+        vec!["some:starts-with=C"],
+        vec!["some:contains-any=JK", "no:contains=AE"],
+        vec!["every:contains-any=AEIOU", "some:letter-at=2,A", "some:ends-with=E"]
+    ];
+    for minimality in [Minimality::All, Minimality::Irreducible] {
+        let finder = common::wordle_finder().minimality(minimality);
+        let all = finder.find().unwrap();
+
+        for constraint_set in &constraint_sets {
+            let constraints: Vec<Constraint> = constraint_set.iter().map(|c| c.parse().unwrap()).collect();
+            let constrained = finder.clone().constraints(constraints.iter().cloned());
+            let expected = text(all.iter().filter(|solution| constraints.iter().all(|c| meets(solution, c))));
+
+            assert!(!expected.is_empty());
+            assert_eq!(text(&constrained.find().unwrap()), expected);
+            assert_eq!(constrained.count().unwrap().total(), expected.len() as u64);
+        }
+    }
+}
+
+#[test]
+fn each_predicate_prunes_a_small_list() {
+    let finder = PangramFinder::new(["ABC", "DEF", "AD", "BCEF", "CF", "ABDE"])
+        .alphabet("ABCDEF".parse::<Alphabet>().unwrap())
+        .max_words(2)
+        .minimality(Minimality::Irreducible);
+    let found = |constraints: &[&str]| {
+        let constraints = constraints.iter().map(|c| c.parse::<Constraint>().unwrap());
+        text(&finder.clone().constraints(constraints).find().unwrap())
+    };
+
+    assert_eq!(found(&[]), ["ABC DEF", "ABDE BCEF", "ABDE CF", "AD BCEF"]);
+    assert_eq!(found(&["some:starts-with=AB"]), ["ABC DEF", "ABDE BCEF", "ABDE CF"]);
+    assert_eq!(found(&["no:contains=CF"]), ["ABC DEF"]);
+    assert_eq!(found(&["every:contains-any=AC"]), ["ABDE BCEF", "ABDE CF", "AD BCEF"]);
+    assert_eq!(found(&["some:letter-at=2,D", "every:ends-with=F"]), Vec::<String>::new());
+    assert_eq!(found(&["some:letter-at=2,D", "some:ends-with=F"]), ["AD BCEF"]);
+    assert_eq!(found(&["some:word=cf"]), ["ABDE CF"]);
+}
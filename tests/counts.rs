@@ -0,0 +1,27 @@
+use pangram_finder::{Alphabet, Minimality, PangramFinder};
+
+mod common;
+
+#[test]
+fn counts_match_solutions_found() {
+    let finder = common::wordle_finder().min_words(3);
+    let solutions = finder.find().unwrap();
+    for threads in [1, 4] {
+        let counts = finder.clone().threads(threads).count().unwrap();
+        assert_eq!(counts.total(), solutions.len() as u64);
+        for (size, count) in counts.by_size() {
+            assert_eq!(count, solutions.iter().filter(|solution| solution.len() == size).count() as u64);
+        }
+    }
+}
+
+#[test]
+fn anagrams_multiply_the_count() {
+    let finder = PangramFinder::new(["ABC", "CAB", "BCA", "DEF", "FED", "ABCDEF"])
+        .alphabet("ABCDEF".parse::<Alphabet>().unwrap())
+        .max_words(2)
+        .minimality(Minimality::Irreducible);
+    let counts = finder.count().unwrap();
+    assert_eq!(counts.by_size().collect::<Vec<_>>(), [(1, 1), (2, 3 * 2)]);
+    assert_eq!(counts.total(), finder.find().unwrap().len() as u64);
+}
@@ -0,0 +1,24 @@
+use pangram_finder::{Error, PangramFinder};
+
+// Nothing contains Q
+const WORDS: [&str; 5] = ["ABCDE", "FGHIJ", "KLMNO", "PRST", "UVWXYZ"];
+
+#[test]
+fn letters_no_word_contains_are_an_error() {
+    let finder = PangramFinder::new(WORDS);
+    assert_eq!(finder.missing_letters(), ['Q']);
+    assert_eq!(finder.find().unwrap_err(), Error::AlphabetNotCovered { missing: vec!['Q'] });
+    assert_eq!(finder.count().unwrap_err(), Error::AlphabetNotCovered { missing: vec!['Q'] });
+}
+
+#[test]
+fn missing_letters_can_be_allowed() {
+    let solutions = PangramFinder::new(WORDS).allow_missing_letters(true).find().unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "ABCDE FGHIJ KLMNO PRST UVWXYZ (missing Q)");
+
+    // Without allowing them, missing letters count towards the near-pangram limit
+    let solutions = PangramFinder::new(WORDS).max_missing_letters(1).find().unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].missing_letters(), ['Q']);
+}
@@ -0,0 +1,24 @@
+use pangram_finder::{Alphabet, PangramFinder};
+
+fn finder<const N: usize>(words: [&str; N]) -> PangramFinder {
+    PangramFinder::new(words).alphabet("ABCDEF".parse::<Alphabet>().unwrap()).max_words(3)
+}
+
+fn solutions(finder: PangramFinder) -> Vec<String> {
+    finder.find().unwrap().iter().map(|solution| solution.to_string()).collect()
+}
+
+#[test]
+fn disjoint_pangrams_share_no_letters() {
+    let words = ["ABC", "DEF", "CDEF", "AB"];
+    assert_eq!(solutions(finder(words)), ["AB CDEF", "ABC CDEF", "ABC DEF", "AB ABC CDEF", "AB ABC DEF"]);
+    assert_eq!(solutions(finder(words).disjoint(true)), ["AB CDEF", "ABC DEF"]);
+}
+
+#[test]
+fn distinct_letters_leaves_out_words_with_repeats() {
+    let words = ["ABCA", "DEF", "AB", "CDEF"];
+    let finder = finder(words).distinct_letters(true);
+    assert_eq!(finder.number_of_words(), 3);
+    assert_eq!(solutions(finder), ["AB CDEF"]);
+}
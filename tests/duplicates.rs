@@ -0,0 +1,41 @@
+use itertools::Itertools;
+use pangram_finder::{Alphabet, Minimality, PangramFinder};
+
+mod common;
+
+#[test]
+fn groups_reachable_in_several_orders_are_found_once() {
+    // {AB, AC} can be reached by selecting either word first
+    let solutions = PangramFinder::new(["AB", "AC", "BC"])
+        .alphabet("ABC".parse::<Alphabet>().unwrap())
+        .max_words(3)
+        .find()
+        .unwrap();
+    let solutions: Vec<String> = solutions.iter().map(|solution| solution.to_string()).collect();
+    assert_eq!(solutions, ["AB AC", "AB BC", "AC BC"]);
+}
+
+// Counts from the previous implementation, which found every order of each pangram and then
+// removed the repeats with `Itertools::unique`
+#[test]
+fn counts_match_deduplicated_search() {
+    let cases = [
+        (4, 0, false, Minimality::All, 21),
+        (4, 1, false, Minimality::All, 1841),
+        (3, 2, false, Minimality::All, 4),
+        (5, 0, false, Minimality::All, 67915),
+        (5, 0, false, Minimality::Irreducible, 66061),
+        (4, 0, true, Minimality::All, 2)
+    ];
+    for (max_words, max_missing_letters, disjoint, minimality, expected) in cases {
+        let finder = common::wordle_finder()
+            .max_words(max_words)
+            .max_missing_letters(max_missing_letters)
+            .disjoint(disjoint);
+        let solutions = finder.clone().minimality(minimality).find().unwrap();
+        assert_eq!(solutions.len(), expected, "max_words = {max_words}, max_missing_letters = {max_missing_letters}");
+
+        let all_solutions = finder.find().unwrap();
+        assert_eq!(all_solutions.iter().unique().count(), all_solutions.len());
+    }
+}
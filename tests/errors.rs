@@ -0,0 +1,82 @@
+use pangram_finder::{Column, Error, NonLetters, PangramFinder, WordListEntry, WordListError, WordListFormat};
+
+const WORDS: [&str; 5] = ["ABCDE", "FGHIJ", "KLMNO", "PQRST", "UVWXYZ"];
+
+#[test]
+fn an_empty_word_list_is_an_error() {
+    let words: [&str; 3] = ["", "  ", "1234"];
+    assert_eq!(PangramFinder::new(words).find().unwrap_err(), Error::EmptyWordList);
+}
+
+#[test]
+fn impossible_solution_sizes_are_an_error() {
+    let error = PangramFinder::new(WORDS).min_words(4).max_words(3).count().unwrap_err();
+    assert_eq!(error, Error::SolutionSizeOutOfRange { min: 4, max: 3 });
+
+    let error = PangramFinder::new(WORDS).max_words(0).find().unwrap_err();
+    assert_eq!(error, Error::SolutionSizeOutOfRange { min: 1, max: 0 });
+}
+
+#[test]
+fn huge_maximum_sizes_are_clamped() {
+    let finder = PangramFinder::new(WORDS).max_words(usize::MAX);
+    assert_eq!(finder.max_solution_size(), 26);
+    assert_eq!(finder.count().unwrap().max_words(), 26);
+    assert_eq!(finder.find().unwrap().len(), 1);
+
+    let finder = PangramFinder::new(WORDS).require_words(["QUIZ"]).max_words(usize::MAX);
+    assert_eq!(finder.max_solution_size(), 27);
+
+    let error = PangramFinder::new(WORDS).min_words(27).max_words(usize::MAX).count().unwrap_err();
+    assert_eq!(error, Error::SolutionSizeOutOfRange { min: 27, max: 26 });
+}
+
+#[test]
+fn failing_on_non_letters_reports_the_line() {
+    let words = ["ABCDE", "FGHIJ", "KLM-NO", "PQRST", "UVWXYZ"];
+    assert_eq!(PangramFinder::new(words).find().unwrap().len(), 1);
+
+    let error = PangramFinder::new(words).non_letters(NonLetters::Fail).find().unwrap_err();
+    assert_eq!(error, Error::InvalidWord { line: 3, word: "KLM-NO".to_owned() });
+    assert_eq!(error.to_string(), "line 3: \"KLM-NO\" has characters outside the alphabet");
+}
+
+#[test]
+fn invalid_words_report_the_line_in_every_format() {
+    let inputs = [
+        (WordListFormat::Text, "ABCDE\n\nFGHIJ\nKLM-NO\n", Column::default(), false, 4),
+        (WordListFormat::Csv, "word,note\nABCDE,\nFGHIJ,\"two\nlines\"\nKLM-NO,\n", Column::Name("word".to_owned()), true, 5),
+        (WordListFormat::Tsv, "word\tcount\nABCDE\t3\nKLM-NO\t1\n", Column::Index(0), true, 3),
+        (WordListFormat::Json, "[\n  \"ABCDE\",\n  \"FGHIJ\",\n  \"KLM-NO\"\n]", Column::default(), false, 4),
+        (WordListFormat::Frequency, "ABCDE\t3\nKLM-NO\t50\nFGHIJ\t100\n", Column::default(), false, 2)
+    ];
+
+    for (format, input, column, has_header, line) in inputs {
+        let words = format.parse(input, &column, has_header).unwrap();
+        let error = PangramFinder::from_word_list(words).non_letters(NonLetters::Fail).find().unwrap_err();
+        assert_eq!(error, Error::InvalidWord { line, word: "KLM-NO".to_owned() }, "{format:?}");
+    }
+}
+
+#[test]
+fn invalid_encoding_reports_the_line() {
+    let error = WordListFormat::Text.parse_bytes(b"CIGAR\nREBUT\nSI\xffSY\n", &Column::default(), false).unwrap_err();
+    assert_eq!(error, Error::InvalidEncoding { line: 3 });
+
+    let words = WordListFormat::Text.parse_bytes(b"\xef\xbb\xbfCIGAR\nREBUT\n", &Column::default(), false).unwrap();
+    assert_eq!(words[0], WordListEntry { word: "CIGAR".to_owned(), line: 1 });
+    assert_eq!(words.len(), 2);
+}
+
+#[test]
+fn word_list_errors_are_wrapped() {
+    let error = WordListFormat::Json.parse_bytes(b"[\"CIGAR\",\n3]", &Column::default(), false).unwrap_err();
+    assert!(matches!(error, Error::WordList(WordListError::Malformed { line: 2, .. })), "{error:?}");
+}
+
+#[test]
+fn missing_files_are_io_errors() {
+    let path = std::path::Path::new("does/not/exist.txt");
+    let error = WordListFormat::Text.read(path, &Column::default(), false).unwrap_err();
+    assert!(matches!(&error, Error::Io { path: Some(p), kind: std::io::ErrorKind::NotFound, .. } if p == path), "{error:?}");
+}
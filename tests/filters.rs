@@ -0,0 +1,39 @@
+use pangram_finder::{Column, PangramFinder, WordListFormat};
+
+const WORDS: [&str; 8] = ["ABCDE", "FGHIJ", "KLMNO", "PQRST", "UVWXYZ", "ABCDEFGHIJKLM", "NOPQRSTUVWXYZ", "AB"];
+
+#[test]
+fn word_lengths_filter_the_candidates() {
+    let finder = PangramFinder::new(WORDS).word_lengths(5..=6);
+    assert_eq!(finder.number_of_words(), 5);
+    let solutions = finder.find().unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "ABCDE FGHIJ KLMNO PQRST UVWXYZ");
+
+    let finder = PangramFinder::new(WORDS).word_lengths(13..=13);
+    assert_eq!(finder.find().unwrap().len(), 1);
+}
+
+#[test]
+fn most_frequent_keeps_the_first_words() {
+    let words = WordListFormat::Frequency
+        .parse("NOPQRSTUVWXYZ\t5\nAB\t90\nABCDEFGHIJKLM\t3\nABCDE\t100\n", &Column::default(), false)
+        .unwrap();
+    let names: Vec<&str> = words.iter().map(|entry| entry.word.as_str()).collect();
+    assert_eq!(names, ["ABCDE", "AB", "NOPQRSTUVWXYZ", "ABCDEFGHIJKLM"]);
+
+    let finder = PangramFinder::new(&names).most_frequent(3).allow_missing_letters(true);
+    assert_eq!(finder.number_of_words(), 3);
+    assert_eq!(finder.missing_letters(), vec!['F', 'G', 'H', 'I', 'J', 'K', 'L', 'M']);
+
+    // Length is filtered first, so the three most frequent long words are searched
+    let solutions = PangramFinder::from_word_list(words).word_lengths(5..=13).most_frequent(3).find().unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "ABCDEFGHIJKLM NOPQRSTUVWXYZ");
+}
+
+#[test]
+fn required_words_are_kept_beyond_the_most_frequent() {
+    let finder = PangramFinder::new(WORDS).most_frequent(1).require_words(["UVWXYZ"]).allow_missing_letters(true);
+    assert_eq!(finder.number_of_words(), 2);
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use pangram_finder::{Alphabet, Minimality, PangramFinder, Solution};
+
+mod common;
+
+fn letters(words: &[&str]) -> HashSet<char> {
+    words.iter().flat_map(|word| word.chars()).collect()
+}
+
+fn is_reducible(solution: &Solution) -> bool {
+    let words: Vec<&str> = solution.words().iter().map(|word| word.name()).collect();
+    (0..words.len()).any(|skipped| {
+        let others: Vec<&str> = [&words[..skipped], &words[skipped + 1..]].concat();
+        letters(&others) == letters(&words)
+    })
+}
+
+// Every group of up to max_words words (no two with the same letters) that covers the alphabet,
+// as text, split into irreducible and reducible groups
+fn brute_force(words: &[&str], alphabet: &str, max_words: usize) -> (Vec<String>, Vec<String>) {
+    let (mut irreducible, mut reducible): (Vec<String>, Vec<String>) = (1..=max_words)
+        .flat_map(|size| words.iter().copied().combinations(size))
+        .filter(|group| group.iter().map(|&word| word.chars().sorted().dedup().collect::<String>()).all_unique())
+        .filter(|group| letters(group) == alphabet.chars().collect())
+        .map(|group| group.into_iter().sorted().collect::<Vec<&str>>())
+        .partition_map(|group| {
+            let text = group.join(" ");
+            let others_cover = (0..group.len()).any(|skipped| {
+                let others: Vec<&str> = [&group[..skipped], &group[skipped + 1..]].concat();
+                letters(&others) == letters(&group)
+            });
+            if others_cover { itertools::Either::Right(text) } else { itertools::Either::Left(text) }
+        });
+    irreducible.sort();
+    reducible.sort();
+    (irreducible, reducible)
+}
+
+#[test]
+fn irreducible_and_reducible_pangrams_split_all_pangrams() {
+    let finder = common::wordle_finder();
+    let all = finder.find().unwrap();
+    let irreducible = finder.clone().minimality(Minimality::Irreducible).find().unwrap();
+    let reducible = finder.clone().minimality(Minimality::Reducible).find().unwrap();
+
+    assert!(irreducible.iter().all(|solution| !is_reducible(solution)));
+    assert!(reducible.iter().all(is_reducible));
+    assert_eq!(irreducible.len() + reducible.len(), all.len());
+}
+
+#[test]
+fn pangrams_with_a_covered_word_are_reducible() {
+    let words = ["ABC", "DEF", "AD", "BCEF", "ABCDEF", "CF", "ABDE"];
+    let finder = PangramFinder::new(words).alphabet("ABCDEF".parse::<Alphabet>().unwrap()).max_words(2);
+    let solutions = |minimality| common::text(&finder.clone().minimality(minimality).find().unwrap());
+    assert_eq!(solutions(Minimality::Irreducible), ["ABCDEF", "ABC DEF", "ABDE BCEF", "ABDE CF", "AD BCEF"]);
+    assert_eq!(solutions(Minimality::Reducible), [
+        "ABC ABCDEF", "ABCDEF ABDE", "ABCDEF AD", "ABCDEF BCEF", "ABCDEF CF", "ABCDEF DEF"
+    ]);
+
+    let (irreducible, reducible) = brute_force(&words, "ABCDEF", 2);
+    assert_eq!(solutions(Minimality::Irreducible).into_iter().sorted().collect::<Vec<_>>(), irreducible);
+    assert_eq!(solutions(Minimality::Reducible).into_iter().sorted().collect::<Vec<_>>(), reducible);
+}
+
+#[test]
+fn reducible_pangrams_match_a_brute_force_search() {
+    let alphabet = "ABCDEF";
+    let words: Vec<String> = common::wordle_words()
+        .into_iter()
+        .map(|word| word.chars().filter(|&letter| alphabet.contains(letter)).collect::<String>())
+        .filter(|word| !word.is_empty())
+        .unique()
+        .take(40)
+        .collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    let (irreducible, reducible) = brute_force(&words, alphabet, 3);
+    assert!(!reducible.is_empty());
+
+    let finder = PangramFinder::new(&words).alphabet(alphabet.parse::<Alphabet>().unwrap()).max_words(3);
+    for (minimality, expected) in [(Minimality::Irreducible, irreducible), (Minimality::Reducible, reducible)] {
+        let solutions = finder.clone().minimality(minimality).find().unwrap();
+        assert_eq!(common::text(&solutions).into_iter().sorted().collect::<Vec<_>>(), expected, "{minimality:?}");
+    }
+}
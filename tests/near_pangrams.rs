@@ -0,0 +1,30 @@
+use pangram_finder::{Alphabet, PangramFinder};
+
+#[test]
+fn near_pangrams_can_miss_the_last_letters_searched() {
+    // E is the most common letter, so it is searched last
+    let words = ["ABCD", "E", "AE", "BE", "CE", "DE"];
+    let finder = PangramFinder::new(words)
+        .alphabet(Alphabet::new("ABCDE".chars()).unwrap())
+        .max_missing_letters(1)
+        .max_words(1);
+
+    let solutions = finder.find().unwrap();
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].to_string(), "ABCD (missing E)");
+    assert_eq!(solutions[0].missing_letters(), ['E']);
+}
+
+#[test]
+fn near_pangrams_are_reported_alongside_pangrams() {
+    let words = ["ABCD", "E", "AE", "BE", "CE", "DE"];
+    let finder = PangramFinder::new(words)
+        .alphabet(Alphabet::new("ABCDE".chars()).unwrap())
+        .max_missing_letters(1)
+        .max_words(2);
+
+    let solutions = finder.find().unwrap();
+    let complete = solutions.iter().filter(|solution| solution.missing_letters().is_empty()).count();
+    assert_eq!(complete, 5);
+    assert_eq!(solutions.len() - complete, 1);
+}
@@ -0,0 +1,92 @@
+use pangram_finder::{OutputFormat, PangramCounts, PangramFinder, Solution, SolutionWriter, Summary};
+use serde_json::{json, Value};
+
+fn write_all(solutions: &[Solution], format: OutputFormat) -> String {
+    let mut counts = PangramCounts::new(1..=5);
+    let mut writer = SolutionWriter::new(vec![], format).unwrap();
+    for solution in solutions {
+        writer.write(solution).unwrap();
+        counts.add(solution.len());
+    }
+    let output = writer.finish(&Summary { counts, words_searched: 6 }).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+fn find_solutions() -> Vec<Solution> {
+    PangramFinder::new(["ABCDE", "FGHIJ", "KLMNO", "PQRST", "UVWXYZ", "ABCDEFGHIJ"])
+        .max_words(5)
+        .find()
+        .unwrap()
+}
+
+#[test]
+fn json_output_has_stable_fields() {
+    let output = write_all(&find_solutions(), OutputFormat::Json);
+    let output: Value = serde_json::from_str(&output).unwrap();
+
+    assert_eq!(output["solutions"][0], json!({
+        "words": ["ABCDEFGHIJ", "KLMNO", "PQRST", "UVWXYZ"],
+        "size": 4,
+        "letters_covered": 26,
+        "overlap": 0,
+        "length": 26,
+        "rank": 5 + 2 + 3 + 4,
+        "missing_letters": []
+    }));
+    assert_eq!(output["solutions"].as_array().unwrap().len(), 3);
+    assert_eq!(output["summary"]["pangrams"], 3);
+    assert_eq!(output["summary"]["by_size"][4], json!({ "size": 5, "count": 2 }));
+}
+
+#[test]
+fn csv_output_has_a_header_and_a_row_per_solution() {
+    let output = write_all(&find_solutions(), OutputFormat::Csv);
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines[0], "words,size,letters_covered,overlap,length,rank,missing_letters");
+    assert_eq!(lines[1], "ABCDEFGHIJ KLMNO PQRST UVWXYZ,4,26,0,26,14,");
+    assert_eq!(lines.len(), 1 + 3);
+}
+
+#[test]
+fn csv_output_runs_missing_letters_together() {
+    let solutions = PangramFinder::new(["ABCDEFGHIJKLMNOPQRSTUVW"]).max_missing_letters(3).find().unwrap();
+    let output = write_all(&solutions, OutputFormat::Csv);
+
+    assert_eq!(output.lines().nth(1), Some("ABCDEFGHIJKLMNOPQRSTUVW,1,23,0,23,0,XYZ"));
+}
+
+#[test]
+fn ndjson_output_has_a_line_per_solution_then_the_summary() {
+    let output = write_all(&find_solutions(), OutputFormat::Ndjson);
+    let lines: Vec<Value> = output.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+
+    assert_eq!(lines.len(), 3 + 1);
+    assert_eq!(lines[0], json!({
+        "words": ["ABCDEFGHIJ", "KLMNO", "PQRST", "UVWXYZ"],
+        "size": 4,
+        "letters_covered": 26,
+        "overlap": 0,
+        "length": 26,
+        "rank": 5 + 2 + 3 + 4,
+        "missing_letters": []
+    }));
+    assert_eq!(lines[3]["summary"]["pangrams"], 3);
+    assert_eq!(lines[3]["summary"]["words_searched"], 6);
+}
+
+#[test]
+fn summaries_are_written_on_their_own() {
+    let mut counts = PangramCounts::new(1..=3);
+    counts.add(2);
+    let summary = Summary { counts, words_searched: 4 };
+
+    let mut csv = vec![];
+    summary.write(&mut csv, OutputFormat::Csv).unwrap();
+    assert_eq!(String::from_utf8(csv).unwrap(), "size,count\n1,0\n2,1\n3,0\n");
+
+    let mut ndjson = vec![];
+    summary.write(&mut ndjson, OutputFormat::Ndjson).unwrap();
+    let ndjson: Value = serde_json::from_slice(&ndjson).unwrap();
+    assert_eq!(ndjson["summary"]["pangrams"], 1);
+}
@@ -0,0 +1,40 @@
+use pangram_finder::{Alphabet, Minimality, PangramFinder, SortBy};
+
+mod common;
+
+// Each word's rank is its position in the list
+fn finder() -> PangramFinder {
+    PangramFinder::new(["ABCD", "CDEF", "ABC", "DEF", "ABCDEF"])
+        .alphabet("ABCDEF".parse::<Alphabet>().unwrap())
+        .max_words(2)
+        .minimality(Minimality::Irreducible)
+}
+
+fn sorted_by(sort_by: SortBy) -> Vec<String> {
+    common::text(&finder().sort_by(sort_by).find().unwrap())
+}
+
+#[test]
+fn each_metric_sorts_best_first() {
+    assert_eq!(sorted_by(SortBy::Words), ["ABCDEF", "ABC CDEF", "ABC DEF", "ABCD CDEF", "ABCD DEF"]);
+    assert_eq!(sorted_by(SortBy::Length), ["ABCDEF", "ABC DEF", "ABC CDEF", "ABCD DEF", "ABCD CDEF"]);
+    // Ties are broken by number of words, then alphabetically
+    assert_eq!(sorted_by(SortBy::Overlap), ["ABCDEF", "ABC DEF", "ABC CDEF", "ABCD DEF", "ABCD CDEF"]);
+    assert_eq!(sorted_by(SortBy::Rank), ["ABCD CDEF", "ABC CDEF", "ABCD DEF", "ABCDEF", "ABC DEF"]);
+
+    let scores: Vec<(usize, usize)> = finder()
+        .sort_by(SortBy::Rank)
+        .find()
+        .unwrap()
+        .iter()
+        .map(|solution| (solution.score().overlap, solution.score().rank))
+        .collect();
+    assert_eq!(scores, [(2, 1), (1, 3), (1, 3), (0, 4), (0, 5)]);
+}
+
+#[test]
+fn top_keeps_the_best_solutions() {
+    let top = finder().sort_by(SortBy::Rank).top(2).find().unwrap();
+    assert_eq!(common::text(&top), ["ABCD CDEF", "ABC CDEF"]);
+    assert!(finder().top(0).find().unwrap().is_empty());
+}
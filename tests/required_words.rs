@@ -0,0 +1,78 @@
+use pangram_finder::{Alphabet, Minimality, PangramFinder, Solution};
+
+mod common;
+use common::text;
+
+fn containing(solutions: &[Solution], word: &str, contains: bool) -> Vec<String> {
+    text(solutions.iter().filter(|solution| solution.words().iter().any(|w| w.name() == word) == contains))
+}
+
+#[test]
+fn required_and_excluded_words_match_filtering_all_pangrams() {
+    for minimality in [Minimality::All, Minimality::Irreducible] {
+        let finder = common::wordle_finder().minimality(minimality);
+        let all = finder.find().unwrap();
+        let word = all[all.len() / 2].words()[0].name().to_owned();
+
+        let required = finder.clone().require_words([&word]).find().unwrap();
+        let excluded = finder.clone().exclude_words([&word]).find().unwrap();
+
+        assert!(!required.is_empty());
+        assert_eq!(text(&required), containing(&all, &word, true));
+        assert_eq!(text(&excluded), containing(&all, &word, false));
+    }
+}
+
+#[test]
+fn required_words_may_be_redundant() {
+    // The other words always cover AA's letters first, so no pangram the search finds contains it
+    let words = ["F", "EADB", "ABEE", "AA", "CEAD", "E"];
+    let finder = PangramFinder::new(words)
+        .alphabet("ABCDEFG".parse::<Alphabet>().unwrap())
+        .allow_missing_letters(true)
+        .max_missing_letters(1)
+        .max_words(4);
+    let all = finder.find().unwrap();
+    assert_eq!(containing(&all, "AA", true), Vec::<String>::new());
+    assert_eq!(text(&finder.clone().require_words(["AA"]).find().unwrap()), [
+        "AA ABEE CEAD (missing F, G)",
+        "AA CEAD EADB (missing F, G)",
+        "AA CEAD F (missing B, G)",
+        "AA EADB F (missing C, G)",
+        "AA ABEE CEAD F (missing G)",
+        "AA ABEE EADB F (missing C, G)",
+        "AA CEAD EADB F (missing G)"
+    ]);
+
+    for minimality in [Minimality::All, Minimality::Reducible, Minimality::Irreducible] {
+        let finder = finder.clone().minimality(minimality);
+        let all = finder.find().unwrap();
+        for word in words {
+            let required = finder.clone().require_words([word]).find().unwrap();
+            assert_eq!(containing(&required, word, false), Vec::<String>::new(), "{minimality:?}, {word}");
+            if minimality == Minimality::All {
+                // Pangrams where it's redundant are only found when it's required
+                let required = text(&required);
+                assert!(containing(&all, word, true).iter().all(|solution| required.contains(solution)),
+                        "{minimality:?}, {word}");
+            } else {
+                assert_eq!(text(&required), containing(&all, word, true), "{minimality:?}, {word}");
+            }
+        }
+    }
+}
+
+#[test]
+fn requiring_one_of_two_anagrams_matches_filtering() {
+    let words = ["EDAG", "F", "EGFB", "D", "ECG", "BD", "FGBE"];
+    let finder = PangramFinder::new(words)
+        .alphabet("ABCDEFG".parse::<Alphabet>().unwrap())
+        .max_missing_letters(1)
+        .max_words(4)
+        .minimality(Minimality::Reducible);
+    let all = finder.find().unwrap();
+    for word in ["EGFB", "FGBE"] {
+        let required = finder.clone().require_words([word]).find().unwrap();
+        assert_eq!(text(&required), containing(&all, word, true), "{word}");
+    }
+}
@@ -0,0 +1,53 @@
+use pangram_finder::{Alphabet, CaseMapping, NonLetters, PangramFinder, SanitizeReport};
+
+const WORDS: [&str; 7] = ["don't", "x-ray", "  Cigar ", "", "CIGAR", "123", "abracadabra"];
+
+#[test]
+fn each_policy_reports_what_it_changed() {
+    let finder = PangramFinder::new(WORDS).max_word_length(10);
+
+    assert_eq!(finder.clone().non_letters(NonLetters::Strip).sanitize_report(), SanitizeReport {
+        lines: 7, unchanged: 3, folded: 0, stripped: 2, split: 0, rejected: 0, blank: 2, wrong_length: 1, duplicates: 1
+    });
+    assert_eq!(finder.clone().non_letters(NonLetters::Reject).sanitize_report(), SanitizeReport {
+        lines: 7, unchanged: 3, folded: 0, stripped: 0, split: 0, rejected: 3, blank: 1, wrong_length: 1, duplicates: 1
+    });
+    assert_eq!(finder.clone().non_letters(NonLetters::Split).sanitize_report(), SanitizeReport {
+        lines: 7, unchanged: 3, folded: 0, stripped: 0, split: 2, rejected: 0, blank: 2, wrong_length: 1, duplicates: 1
+    });
+    // DON, T, X, RAY and CIGAR
+    assert_eq!(finder.clone().non_letters(NonLetters::Split).number_of_words(), 5);
+    assert_eq!(finder.min_word_length(2).non_letters(NonLetters::Split).number_of_words(), 3);
+}
+
+#[test]
+fn changing_a_setting_sanitizes_the_word_list_again() {
+    // The word list is only sanitized once, so settings changed after using it must not be lost
+    let finder = PangramFinder::new(WORDS).max_word_length(10);
+    // DONT, XRAY and CIGAR
+    assert_eq!(finder.number_of_words(), 3);
+    // DON and RAY
+    let finder = finder.non_letters(NonLetters::Split).min_word_length(2).exclude_words(["cigar"]);
+    assert_eq!(finder.number_of_words(), 2);
+    assert_eq!(finder.sanitize_report().split, 2);
+}
+
+#[test]
+fn folding_maps_accented_letters_into_the_alphabet() {
+    let names = |finder: PangramFinder| -> Vec<String> {
+        finder.max_words(1).allow_missing_letters(true).max_missing_letters(29).find().unwrap()
+            .iter()
+            .map(|solution| solution.words()[0].name().to_owned())
+            .collect()
+    };
+    // The second café is spelled with a combining accent
+    let words = ["café", "cafe\u{301}", "encyclopædia", "ıspanak", "istanbul"];
+
+    assert_eq!(names(PangramFinder::new(words)), ["CAF", "ENCYCLOPDIA", "ISPANAK", "ISTANBUL"]);
+    assert_eq!(names(PangramFinder::new(words).fold_diacritics(true)),
+               ["CAFE", "ENCYCLOPAEDIA", "ISPANAK", "ISTANBUL"]);
+    assert_eq!(names(PangramFinder::new(&words[..2]).alphabet("ACEÉF".parse().unwrap()).fold_diacritics(true)),
+               ["CAFÉ"]);
+    assert_eq!(names(PangramFinder::new(&words[3..]).alphabet(Alphabet::turkish()).case_mapping(CaseMapping::Turkic)),
+               ["ISPANAK", "İSTANBUL"]);
+}
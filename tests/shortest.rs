@@ -0,0 +1,23 @@
+use pangram_finder::{Alphabet, PangramFinder, SortBy};
+
+mod common;
+
+#[test]
+fn shortest_pangrams_are_the_shortest_of_all_pangrams() {
+    let finder = common::wordle_finder().sort_by(SortBy::Length);
+    let all = finder.find().unwrap();
+    let shortest_length = all[0].score().length;
+    let expected: Vec<_> = all.iter().filter(|solution| solution.score().length == shortest_length).cloned().collect();
+
+    assert!(!expected.is_empty());
+    assert_eq!(finder.find_shortest().unwrap(), expected);
+}
+
+#[test]
+fn ties_for_shortest_are_all_reported() {
+    let finder = PangramFinder::new(["ABCD", "CDEF", "ABC", "DEF", "ABCDEF"])
+        .alphabet("ABCDEF".parse::<Alphabet>().unwrap())
+        .max_words(3);
+    assert_eq!(common::text(&finder.find_shortest().unwrap()), ["ABCDEF", "ABC DEF"]);
+    assert_eq!(common::text(&finder.min_words(2).find_shortest().unwrap()), ["ABC DEF"]);
+}
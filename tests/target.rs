@@ -0,0 +1,50 @@
+use std::collections::HashSet;
+
+use pangram_finder::{Alphabet, Error, PangramFinder};
+
+mod common;
+
+#[test]
+fn target_pangrams_match_brute_force() {
+    let words = common::wordle_words();
+    let target: HashSet<char> = "STRINGE".chars().collect();
+    let covers = |words: &[&String]| target.iter().all(|letter| words.iter().any(|word| word.contains(*letter)));
+
+    let mut expected = vec![];
+    for (i, first) in words.iter().enumerate() {
+        if covers(&[first]) {
+            expected.push(first.clone());
+            continue
+        }
+        for second in &words[i + 1..] {
+            if !covers(&[second]) && covers(&[first, second]) {
+                let mut pair = [first.as_str(), second.as_str()];
+                pair.sort();
+                expected.push(pair.join(" "));
+            }
+        }
+    }
+    expected.sort();
+
+    let mut found: Vec<String> = PangramFinder::new(&words)
+        .target(Alphabet::new("STRINGER".chars()).unwrap())
+        .max_words(2)
+        .find()
+        .unwrap()
+        .iter()
+        .map(|solution| solution.to_string())
+        .collect();
+    found.sort();
+
+    assert!(!expected.is_empty());
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn a_target_no_word_covers_is_an_error() {
+    // Even when missing letters are allowed, leaving them all out would leave nothing to cover
+    let finder = PangramFinder::new(["ABC", "DEF"]).target(Alphabet::new("Q".chars()).unwrap());
+    let error = finder.clone().allow_missing_letters(true).find().unwrap_err();
+    assert_eq!(error, Error::AlphabetNotCovered { missing: vec!['Q'] });
+    assert_eq!(finder.find().unwrap_err(), Error::AlphabetNotCovered { missing: vec!['Q'] });
+}
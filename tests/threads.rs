@@ -0,0 +1,29 @@
+use pangram_finder::{Alphabet, PangramFinder};
+
+mod common;
+
+#[test]
+fn parallel_search_matches_sequential_search() {
+    let finder = common::wordle_finder();
+    let sequential = finder.clone().threads(1).find().unwrap();
+    for threads in [2, 3, 8] {
+        assert_eq!(finder.clone().threads(threads).find().unwrap(), sequential, "threads = {threads}");
+    }
+}
+
+#[test]
+fn parallel_search_finds_the_pangrams_of_each_branch() {
+    // Every letter is in four words, so the search starts from A, with a branch for each word
+    // containing A
+    let words = ["ABC", "DEF", "AD", "BCEF", "ABCDEF", "CF", "ABDE"];
+    let finder = PangramFinder::new(words)
+        .alphabet("ABCDEF".parse::<Alphabet>().unwrap())
+        .max_words(2);
+    for threads in [1, 2, 8] {
+        let solutions = finder.clone().threads(threads).find().unwrap();
+        let expected = [
+            "ABCDEF", "ABC ABCDEF", "ABC DEF", "ABCDEF ABDE", "ABCDEF AD", "ABDE BCEF", "ABDE CF", "AD BCEF"
+        ];
+        assert_eq!(common::text(&solutions), expected, "threads = {threads}");
+    }
+}
@@ -0,0 +1,62 @@
+use pangram_finder::{Column, WordListEntry, WordListError, WordListFormat};
+
+fn words(entries: Vec<WordListEntry>) -> Vec<String> {
+    entries.into_iter().map(|entry| entry.word).collect()
+}
+
+fn lines(entries: Vec<WordListEntry>) -> Vec<usize> {
+    entries.into_iter().map(|entry| entry.line).collect()
+}
+
+#[test]
+fn every_format_reads_the_same_words() {
+    let expected = vec!["CIGAR", "REBUT", "SISSY"];
+    let inputs = [
+        (WordListFormat::Text, "CIGAR\r\nREBUT\r\nSISSY\r\n", Column::default()),
+        (WordListFormat::Csv, "rank,word\n1,CIGAR\n2,REBUT\n3,SISSY\n", Column::Name("word".to_owned())),
+        (WordListFormat::Tsv, "1\tCIGAR\n2\tREBUT\n3\tSISSY\n", Column::Index(1)),
+        (WordListFormat::Json, r#"["CIGAR", "REBUT", "SISSY"]"#, Column::default()),
+        (WordListFormat::Frequency, "SISSY\t3\nCIGAR\t120\n\nREBUT 45\n", Column::default())
+    ];
+
+    for (format, input, column) in inputs {
+        assert_eq!(words(format.parse(input, &column, false).unwrap()), expected, "{format:?}");
+    }
+}
+
+#[test]
+fn malformed_lines_are_reported() {
+    let error = WordListFormat::Frequency.parse("CIGAR\t120\nREBUT\n", &Column::default(), false).unwrap_err();
+    assert_eq!(error, WordListError::Malformed {
+        line: 2,
+        message: "expected a word and a count, found \"REBUT\"".to_owned()
+    });
+
+    let error = WordListFormat::Csv.parse("rank,word\n", &Column::Name("name".to_owned()), true).unwrap_err();
+    assert_eq!(error, WordListError::MissingColumn("name".to_owned()));
+}
+
+#[test]
+fn header_rows_are_not_read_as_words() {
+    let input = "word,count\nCIGAR,120\nREBUT,45\n";
+    assert_eq!(words(WordListFormat::Csv.parse(input, &Column::Index(0), true).unwrap()), vec!["CIGAR", "REBUT"]);
+    assert_eq!(words(WordListFormat::Csv.parse(input, &Column::Index(0), false).unwrap()), vec!["word", "CIGAR", "REBUT"]);
+
+    let input = "word\tcount\nCIGAR\t120\n";
+    assert_eq!(words(WordListFormat::Tsv.parse(input, &Column::Index(0), true).unwrap()), vec!["CIGAR"]);
+}
+
+#[test]
+fn every_format_reports_the_line_of_each_word() {
+    let inputs = [
+        (WordListFormat::Text, "CIGAR\n\nREBUT\n", Column::default(), false, vec![1, 2, 3]),
+        (WordListFormat::Csv, "rank,word\n1,CIGAR\n2,\"RE\nBUT\"\n3,SISSY\n", Column::Name("word".to_owned()), true, vec![2, 3, 5]),
+        (WordListFormat::Tsv, "word\nCIGAR\nREBUT\n", Column::Index(0), true, vec![2, 3]),
+        (WordListFormat::Json, "[\"CIGAR\",\n\n  \"REBUT\", \"SISSY\"\n]", Column::default(), false, vec![1, 3, 3]),
+        (WordListFormat::Frequency, "SISSY\t3\n\nCIGAR\t120\nREBUT 45\n", Column::default(), false, vec![3, 4, 1])
+    ];
+
+    for (format, input, column, has_header, expected) in inputs {
+        assert_eq!(lines(format.parse(input, &column, has_header).unwrap()), expected, "{format:?}");
+    }
+}